@@ -0,0 +1,51 @@
+//! Compares rendering cards whose faces come from the cache of default faces with rendering cards
+//! whose faces are built from their template on every render.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use itertools::Itertools;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use strum::IntoEnumIterator;
+use tui_cards::{Card, CardSize, CardTheme, Rank, Suit, SymbolPresentation};
+
+const SIZES: [CardSize; 4] = [
+    CardSize::Compact,
+    CardSize::Small,
+    CardSize::Normal,
+    CardSize::Large,
+];
+
+fn render_all(cards: &[Card], buf: &mut Buffer) {
+    for card in cards {
+        black_box(card).render(buf.area, buf);
+    }
+}
+
+fn faces(c: &mut Criterion) {
+    let mut group = c.benchmark_group("faces");
+    for size in SIZES {
+        let cached = Rank::iter()
+            .cartesian_product(Suit::iter())
+            .map(|(rank, suit)| Card::new(rank, suit).size(size))
+            .collect_vec();
+        // a non-default pip symbol keeps the face out of the cache while laying it out the same way
+        let theme = CardTheme::new().pip_suit_symbol(SymbolPresentation::Emoji);
+        let uncached = cached.iter().map(|card| card.theme(theme)).collect_vec();
+        let dimensions = size.dimensions();
+        let mut buf = Buffer::empty(Rect::new(0, 0, dimensions.width, dimensions.height));
+
+        group.bench_function(format!("{size:?}/cached"), |b| {
+            b.iter(|| render_all(&cached, &mut buf));
+        });
+        group.bench_function(format!("{size:?}/uncached"), |b| {
+            b.iter(|| render_all(&uncached, &mut buf));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, faces);
+criterion_main!(benches);
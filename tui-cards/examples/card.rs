@@ -1,11 +1,8 @@
-use itertools::Itertools;
 use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent};
-use ratatui::layout::Rect;
 use ratatui::style::{Color, Stylize};
 use ratatui::widgets::Block;
 use ratatui::Frame;
-use strum::IntoEnumIterator;
-use tui_cards::{Card, Rank, Suit};
+use tui_cards::{CardSize, Deck};
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
@@ -13,34 +10,38 @@ fn main() -> color_eyre::Result<()> {
     // fix problem with skipping the wrong number of characters when drawing cards
     // This is probably a bug in ratatui
     terminal.draw(|frame| frame.render_widget(Block::new().bg(Color::White), frame.area()))?;
+    let mut size = CardSize::Normal;
     loop {
-        if terminal.draw(draw).is_err() {
+        if terminal.draw(|frame| draw(frame, size)).is_err() {
             break;
         }
-        if matches!(
-            event::read()?,
-            Event::Key(KeyEvent {
-                code: KeyCode::Char('q'),
-                ..
-            }),
-        ) {
-            break;
+        if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+            match code {
+                KeyCode::Char('q') => break,
+                KeyCode::Char('s') => size = next_size(size),
+                _ => {}
+            }
         }
     }
     ratatui::restore();
     Ok(())
 }
 
-fn draw(frame: &mut Frame) {
-    let width = frame.area().width / 15 * 15;
-    let height = frame.area().height / 10 * 10;
-    let cards = Suit::iter()
-        .cartesian_product(Rank::iter())
-        .map(|(suit, rank)| Card::new(rank, suit));
-    let x_iter = (0..width).step_by(15);
-    let y_iter = (0..height).step_by(10);
-    for (card, (y, x)) in cards.zip(y_iter.cartesian_product(x_iter)) {
-        let area = Rect::new(x, y, 15, 10);
+const fn next_size(size: CardSize) -> CardSize {
+    match size {
+        CardSize::Glyph => CardSize::Tiny,
+        CardSize::Tiny => CardSize::Compact,
+        CardSize::Compact => CardSize::Small,
+        CardSize::Small => CardSize::Normal,
+        CardSize::Normal => CardSize::Large,
+        CardSize::Large => CardSize::Glyph,
+    }
+}
+
+fn draw(frame: &mut Frame, size: CardSize) {
+    frame.render_widget(Block::new().bg(Color::White), frame.area());
+    let deck = Deck::standard();
+    for (card, area) in deck.layout_in(frame.area(), size, 1) {
         frame.render_widget(&card, area);
     }
 }
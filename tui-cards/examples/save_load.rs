@@ -0,0 +1,56 @@
+//! Saves a shuffled deck to JSON and loads it back, as a save game would.
+//!
+//! Run with `cargo run --example save_load --features serde`. Press any key to quit.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Stylize};
+use ratatui::widgets::Block;
+use ratatui::Frame;
+use tui_cards::{CardSize, Deck};
+
+/// The number of cards from the top of each deck to show.
+const SHOWN: usize = 8;
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut cards = Deck::standard().cards().to_vec();
+    cards.shuffle(&mut StdRng::seed_from_u64(42));
+    let deck = Deck::new(cards);
+
+    let json = serde_json::to_string(&deck)?;
+    let loaded: Deck = serde_json::from_str(&json)?;
+    assert_eq!(loaded, deck, "the loaded deck differs from the saved one");
+
+    let mut terminal = ratatui::init();
+    terminal.draw(|frame| draw(frame, &deck, &loaded))?;
+    ratatui::crossterm::event::read()?;
+    ratatui::restore();
+    Ok(())
+}
+
+fn draw(frame: &mut Frame, saved: &Deck, loaded: &Deck) {
+    frame.render_widget(Block::new().bg(Color::White), frame.area());
+    let card_height = CardSize::Small.dimensions().height;
+    let [saved_area, loaded_area] =
+        Layout::vertical([Constraint::Length(card_height + 2); 2]).areas(frame.area());
+    draw_deck(frame, "Saved", saved, saved_area);
+    draw_deck(frame, "Loaded", loaded, loaded_area);
+}
+
+fn draw_deck(frame: &mut Frame, title: &str, deck: &Deck, area: Rect) {
+    let block = Block::new().title(title).black();
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let card_width = CardSize::Small.dimensions().width;
+    for (x, card) in (inner.x..inner.right())
+        .step_by(usize::from(card_width + 1))
+        .zip(deck.cards().iter().take(SHOWN))
+    {
+        let area = Rect::new(x, inner.y, card_width, inner.height);
+        frame.render_widget(&card.size(CardSize::Small), area);
+    }
+}
@@ -0,0 +1,84 @@
+use strum::IntoEnumIterator;
+
+use crate::Suit;
+
+/// The symbol drawn in place of characters that have no ASCII counterpart, such as the pattern of
+/// a custom card back.
+const FALLBACK: char = '#';
+
+/// Returns the ASCII characters drawn in place of a symbol, keeping its width in columns so the
+/// card's layout is unchanged: the character drawn in the symbol's first cell, and the one drawn
+/// in each other cell of a wide symbol.
+///
+/// Suit symbols in any presentation become their letter, padded with a space when the symbol is
+/// two columns wide, box drawing characters become `+`, `-`, and `|`, and anything else without an
+/// obvious counterpart becomes [`FALLBACK`], repeated to its width.
+pub(crate) fn ascii_symbol(symbol: &str) -> (char, char) {
+    let suit = Suit::iter().find(|suit| {
+        symbol.chars().eq([suit.as_symbol()])
+            || [
+                suit.as_text_symbol(),
+                suit.as_colored_symbol(),
+                suit.as_four_color_symbol(),
+            ]
+            .contains(&symbol)
+    });
+    if let Some(suit) = suit {
+        return (suit.as_letter(), ' ');
+    }
+    let ascii = symbol.chars().next().map_or(' ', ascii_char);
+    (ascii, ascii)
+}
+
+/// Returns the ASCII character drawn in place of the given character.
+const fn ascii_char(c: char) -> char {
+    match c {
+        c if c.is_ascii() => c,
+        '╭' | '╮' | '╰' | '╯' | '├' | '┤' | '┼' => '+',
+        '─' | '╌' => '-',
+        '│' | '╎' => '|',
+        '╱' => '/',
+        '╲' => '\\',
+        '╳' => 'X',
+        '·' => '.',
+        _ => FALLBACK,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unicode_width::UnicodeWidthStr;
+
+    use super::*;
+    use crate::card::symbols;
+
+    /// Returns the line with every symbol replaced by ASCII, as a card drawn with ASCII characters
+    /// only shows it.
+    fn ascii_line(line: &str) -> String {
+        symbols(line)
+            .flat_map(|symbol| {
+                let (first, rest) = ascii_symbol(symbol);
+                let width = symbol.width();
+                std::iter::once(first).chain(std::iter::repeat_n(rest, width.saturating_sub(1)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn borders_and_symbols() {
+        assert_eq!(ascii_line("╭──────╮"), "+------+");
+        assert_eq!(ascii_line("│10 ♠️  │"), "|10 S   |");
+        assert_eq!(ascii_line("│A♥︎ ♦ 🔷️☘️│"), "|AH D D C |");
+        assert_eq!(ascii_line("╰╌╌╌╌╌╌╯"), "+------+");
+    }
+
+    #[test]
+    fn keeps_width() {
+        for line in ["│  🃏  │", "│╱╱╱╱╱╱│", "│░░░░░░│", "中♣️"]
+        {
+            let ascii = ascii_line(line);
+            assert!(ascii.is_ascii(), "{ascii}");
+            assert_eq!(ascii.width(), line.width(), "{line}");
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Giant block character rendering of a card, used for splash and reveal screens.
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+
+use crate::{Card, Rank, Suit};
+
+/// The height of every banner glyph in font pixels.
+const GLYPH_HEIGHT: u16 = 5;
+
+/// The number of terminal columns used for one font pixel at scale 1.
+///
+/// Terminal cells are roughly twice as tall as they are wide, so each pixel is two columns wide to
+/// keep the glyphs from looking squashed.
+const PIXEL_WIDTH: u16 = 2;
+
+/// The number of empty font pixels between two glyphs.
+const GLYPH_GAP: u16 = 1;
+
+type Glyph = [&'static str; GLYPH_HEIGHT as usize];
+
+const ONE: Glyph = [" # ", "## ", " # ", " # ", "###"];
+const ZERO: Glyph = [" ### ", "#   #", "#   #", "#   #", " ### "];
+
+/// The word drawn for jokers, which have no rank or suit to show.
+const JOKER: [Glyph; 5] = [
+    ["#####", "   # ", "   # ", "#  # ", " ##  "],
+    [" ### ", "#   #", "#   #", "#   #", " ### "],
+    ["#   #", "#  # ", "###  ", "#  # ", "#   #"],
+    ["#####", "#    ", "#### ", "#    ", "#####"],
+    ["#### ", "#   #", "#### ", "#  # ", "#   #"],
+];
+
+impl Rank {
+    const fn banner_glyphs(self) -> &'static [Glyph] {
+        match self {
+            Self::Ace => &[[" ### ", "#   #", "#####", "#   #", "#   #"]],
+            Self::Two => &[["#### ", "    #", " ### ", "#    ", "#####"]],
+            Self::Three => &[["#### ", "    #", " ### ", "    #", "#### "]],
+            Self::Four => &[["#   #", "#   #", "#####", "    #", "    #"]],
+            Self::Five => &[["#####", "#    ", "#### ", "    #", "#### "]],
+            Self::Six => &[[" ### ", "#    ", "#### ", "#   #", " ### "]],
+            Self::Seven => &[["#####", "    #", "   # ", "  #  ", "  #  "]],
+            Self::Eight => &[[" ### ", "#   #", " ### ", "#   #", " ### "]],
+            Self::Nine => &[[" ### ", "#   #", " ####", "    #", " ### "]],
+            Self::Ten => &[ONE, ZERO],
+            Self::Jack => &[["#####", "   # ", "   # ", "#  # ", " ##  "]],
+            Self::Queen => &[[" ### ", "#   #", "# # #", "#  # ", " ## #"]],
+            Self::King => &[["#   #", "#  # ", "###  ", "#  # ", "#   #"]],
+        }
+    }
+}
+
+impl Suit {
+    const fn banner_glyph(self) -> Glyph {
+        match self {
+            Self::Spades => ["  #  ", " ### ", "#####", "#####", "  #  "],
+            Self::Hearts => [" # # ", "#####", "#####", " ### ", "  #  "],
+            Self::Diamonds => ["  #  ", " ### ", "#####", " ### ", "  #  "],
+            Self::Clubs => [" ### ", " ### ", "#####", "# # #", "  #  "],
+        }
+    }
+}
+
+impl Card {
+    /// Renders the card as giant block characters filling the given area.
+    ///
+    /// This is meant for splash or reveal moments ("you drew the Ace!") rather than for showing
+    /// cards on a table. The rank and suit are drawn side by side in the card's theme colors,
+    /// scaled up by the largest whole factor that fits the area, and centered. Jokers are drawn as
+    /// the word `JOKER`. A face down card is not revealed: its back is drawn at its regular size
+    /// in the middle of the area.
+    ///
+    /// The smallest unscaled banner is 5 rows tall and 22 columns wide (34 columns for a Ten).
+    /// When the area is smaller than that the banner is drawn at its smallest size from the top
+    /// left corner of the area and clipped to it. Nothing is drawn outside the area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+    /// Card::new(Rank::Ace, Suit::Spades).render_large(buf.area, &mut buf);
+    /// ```
+    pub fn render_large(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        if self.is_face_down() {
+            // the banner would give the card away, so show the back of the card instead
+            let style = Style::new().bg(self.theme.background);
+            for position in area.positions() {
+                buf[position].reset();
+                buf[position].set_style(style);
+            }
+            self.render_centered(area, buf);
+            return;
+        }
+        let glyphs = self.banner_glyphs();
+        let pixels: u16 = glyphs.iter().map(glyph_width).sum::<u16>()
+            + GLYPH_GAP * (glyphs.len() as u16).saturating_sub(1);
+        let scale = (area.width / (pixels * PIXEL_WIDTH))
+            .min(area.height / GLYPH_HEIGHT)
+            .max(1);
+        let cell_width = PIXEL_WIDTH * scale;
+        let x = area.x + area.width.saturating_sub(pixels * cell_width) / 2;
+        let y = area.y + area.height.saturating_sub(GLYPH_HEIGHT * scale) / 2;
+
+        let style = Style::new().fg(self.color()).bg(self.theme.background);
+        for position in area.positions() {
+            buf[position].reset();
+            buf[position].set_style(style);
+        }
+
+        let mut offset = 0;
+        for glyph in &glyphs {
+            draw_glyph(
+                glyph,
+                x.saturating_add(offset * cell_width),
+                y,
+                scale,
+                area,
+                buf,
+            );
+            offset += glyph_width(glyph) + GLYPH_GAP;
+        }
+    }
+
+    /// Returns the glyphs of the banner from left to right: the rank followed by the suit, or the
+    /// word `JOKER` for jokers.
+    fn banner_glyphs(&self) -> Vec<Glyph> {
+        if self.is_joker() {
+            return JOKER.to_vec();
+        }
+        let mut glyphs = self.rank.banner_glyphs().to_vec();
+        glyphs.push(self.suit.banner_glyph());
+        glyphs
+    }
+
+    /// Returns whether the cell at the given column and row of the card lies within the suit's
+    /// banner glyph, drawn unscaled in the middle of the card as the large pip of
+    /// [`AcePip::Large`], and if so whether the cell is a filled pixel.
+    ///
+    /// [`AcePip::Large`]: crate::AcePip::Large
+    pub(crate) fn ace_pip_pixel(&self, column: u16, row: u16) -> Option<bool> {
+        let glyph = self.suit.banner_glyph();
+        let size = self.size.dimensions();
+        let width = glyph_width(&glyph) * PIXEL_WIDTH;
+        let x = column
+            .checked_sub(size.width.saturating_sub(width) / 2)
+            .filter(|x| *x < width)?;
+        let y = row
+            .checked_sub(size.height.saturating_sub(GLYPH_HEIGHT) / 2)
+            .filter(|y| *y < GLYPH_HEIGHT)?;
+        let pixel = glyph[usize::from(y)].as_bytes()[usize::from(x / PIXEL_WIDTH)];
+        Some(pixel == b'#')
+    }
+}
+
+/// Draws the filled pixels of a glyph with its top left corner at `x`, `y`, clipped to the area.
+fn draw_glyph(glyph: &Glyph, x: u16, y: u16, scale: u16, area: Rect, buf: &mut Buffer) {
+    let cell_width = PIXEL_WIDTH * scale;
+    for (row, line) in (0..).zip(glyph) {
+        for (column, pixel) in (0..).zip(line.chars()) {
+            if pixel == ' ' {
+                continue;
+            }
+            let pixel_area = Rect::new(
+                x.saturating_add(column * cell_width),
+                y.saturating_add(row * scale),
+                cell_width,
+                scale,
+            )
+            .intersection(area);
+            for position in pixel_area.positions() {
+                buf[position].set_symbol("█");
+            }
+        }
+    }
+}
+
+fn glyph_width(glyph: &Glyph) -> u16 {
+    glyph[0].len() as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Cell;
+    use ratatui_core::style::Color;
+    use ratatui_core::widgets::Widget;
+
+    use super::*;
+    use crate::{CardSize, Facing, JokerColor};
+
+    fn render(card: Card, area: Rect) -> Buffer {
+        let mut buf = Buffer::empty(area);
+        card.render_large(area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn renders_minimum_size() {
+        let buf = render(Card::new(Rank::Ace, Suit::Spades), Rect::new(0, 0, 22, 5));
+        let mut expected = Buffer::with_lines([
+            "  ██████        ██    ",
+            "██      ██    ██████  ",
+            "██████████  ██████████",
+            "██      ██  ██████████",
+            "██      ██      ██    ",
+        ]);
+        expected.set_style(expected.area, Style::new().black().on_white());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn scales_and_centers() {
+        let buf = render(Card::new(Rank::Two, Suit::Hearts), Rect::new(0, 0, 50, 12));
+        // scale 2: 44 x 10, centered with 3 columns and 1 row of margin
+        assert_eq!(buf[(2, 1)].symbol(), " ");
+        assert_eq!(buf[(3, 1)].symbol(), "█");
+        assert_eq!(buf[(19, 1)].symbol(), " ");
+        assert_eq!(buf[(3, 10)].symbol(), "█");
+        assert_eq!(buf[(3, 11)].symbol(), " ");
+    }
+
+    #[test]
+    fn clips_to_small_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+        Card::new(Rank::Ten, Suit::Clubs).render_large(Rect::new(2, 2, 10, 3), &mut buf);
+        for (index, cell) in buf.content.iter().enumerate() {
+            let (x, y) = buf.pos_of(index);
+            if !Rect::new(2, 2, 10, 3).contains((x, y).into()) {
+                assert_eq!(cell, &Cell::EMPTY, "({x}, {y})");
+            }
+        }
+        assert_eq!(buf[(4, 2)].symbol(), "█");
+    }
+
+    #[test]
+    fn renders_joker() {
+        let joker = Card::joker(JokerColor::Red, CardSize::Normal);
+        let buf = render(joker, Rect::new(0, 0, 58, 5));
+        let top: String = (0..58).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(
+            top,
+            "██████████    ██████    ██      ██  ██████████  ████████  "
+        );
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn face_down_shows_back() {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(CardSize::Small)
+            .facing(Facing::Down);
+        let buf = render(card, Rect::new(0, 0, 22, 5));
+        let mut expected = Buffer::empty(buf.area);
+        expected.set_style(expected.area, Style::new().on_white());
+        card.render(Rect::new(7, 0, 8, 5), &mut expected);
+        assert_eq!(buf, expected);
+        assert!(buf.content.iter().all(|cell| cell.symbol() != "█"));
+    }
+
+    #[test]
+    fn ignores_empty_area() {
+        let buf = render(Card::new(Rank::King, Suit::Diamonds), Rect::new(0, 0, 0, 0));
+        assert!(buf.content.is_empty());
+    }
+}
@@ -0,0 +1,2832 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::iter::{self, zip};
+use std::sync::LazyLock;
+
+use itertools::Itertools;
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Alignment, Position, Rect, Size, VerticalAlignment};
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::{Line, Span, Text};
+use ratatui_core::widgets::Widget;
+use strum::IntoEnumIterator;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::ascii::ascii_symbol;
+use crate::corner::{index_cells, mark_corner_symbols, stack_corners};
+use crate::facing::{back_symbol, BACK_GLYPH};
+use crate::joker::{joker_face, JESTER_PIP, JOKER_LABEL};
+use crate::theme::color_name;
+use crate::{
+    AcePip, CardBack, CardSize, CardTheme, CornerLayout, Facing, JokerColor, PipScale, Rank, Suit,
+    SymbolPresentation,
+};
+
+/// A playing card.
+///
+/// # Equality
+///
+/// There are two notions of equality for cards:
+///
+/// - [`PartialEq`] compares every field: the presentation options, which decide how the card is
+///   rendered, and the [tags](Card::tag) added to the card, which are never rendered. Two cards
+///   that render identically are not equal if their tags differ.
+/// - [`Card::same_card`] compares only the rank and suit, and the color of jokers, which is the
+///   notion most game logic wants ("is this the Ace of Spades?") regardless of how the card is
+///   displayed or tagged.
+///
+/// # Rendering
+///
+/// Rendering never panics and never writes outside the given area or the buffer, whatever the
+/// area's size or position. Empty areas, and areas that lie completely outside the buffer, render
+/// nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{Card, Rank, Suit};
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let card = Card::new(Rank::Ace, Suit::Spades);
+/// frame.render_widget(&card, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+    /// The size the card is rendered at. Defaults to [`CardSize::Normal`].
+    pub size: CardSize,
+    /// Where the card sits horizontally in an area wider than it. Defaults to
+    /// [`Alignment::Left`].
+    pub alignment: Alignment,
+    /// Where the card sits vertically in an area taller than it. Defaults to
+    /// [`VerticalAlignment::Top`].
+    pub vertical_alignment: VerticalAlignment,
+    /// The colors the card is rendered with.
+    pub theme: CardTheme,
+    /// How the rank and suit are arranged in the corners. Defaults to
+    /// [`CornerLayout::SideBySide`].
+    pub corner_layout: CornerLayout,
+    /// Whether the card is rendered as a dim outline, e.g. as a drag and drop preview.
+    pub ghost: bool,
+    /// Whether only the border of the card is drawn, leaving the inside blank.
+    pub outline_only: bool,
+    /// Whether the card is drawn with ASCII characters only, for terminals that can't draw box
+    /// drawing characters or suit symbols.
+    pub ascii: bool,
+    /// Which side of the card is shown. Defaults to [`Facing::Up`].
+    pub facing: Facing,
+    /// The pattern on the back of a face down card, or `None` for the default diagonal hatch.
+    /// Defaults to `None`.
+    pub back: Option<CardBack>,
+    /// Whether the back of a face down card is drawn in its suit's color rather than the usual
+    /// suit agnostic color.
+    pub back_tinted: bool,
+    /// Whether the card is rendered upside down, e.g. for reversed tarot cards.
+    pub reversed: bool,
+    /// Whether court cards are drawn with their detailed art, rather than a single letter.
+    pub court_art: bool,
+    /// Whether [`Card::highlight_style`] is applied to the edge of the card.
+    pub highlighted: bool,
+    /// The style applied to the edge of the card when it is highlighted. Defaults to a green
+    /// foreground.
+    pub highlight_style: Style,
+    /// The symbol and style drawn in the empty cells inside the border of a face up card, if any.
+    /// Defaults to `None`, which leaves the cells blank.
+    pub interior_fill: Option<(char, Style)>,
+    /// The modifier applied to the rank in the corners of the card. Defaults to none.
+    pub rank_emphasis: Modifier,
+    /// The light and dark colors of a beveled border, or `None` for a flat border. Defaults to
+    /// `None`.
+    pub bevel: Option<(Color, Color)>,
+    /// The symbol and style of a marker in the top right corner, or `None` for no marker. Defaults
+    /// to `None`.
+    pub marker: Option<(char, Style)>,
+    /// A bitset of game specific tags, such as wild or trump, which does not affect rendering.
+    /// Defaults to 0. See [`Card::tag`].
+    pub tags: u8,
+    /// The color of the joker this card is, or `None` for a regular card. Defaults to `None`. See
+    /// [`Card::joker`].
+    pub joker: Option<JokerColor>,
+}
+
+/// A problem that keeps a [`Card`] from rendering legibly, returned by [`Card::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CardError {
+    /// The suit is drawn in the same color as the card's background, so its symbols and indices
+    /// are invisible.
+    SuitMatchesBackground(Suit),
+    /// The pattern on the back of the card is drawn in the same color as its background.
+    BackMatchesBackground,
+    /// The pattern on the back of the card has a symbol that does not occupy exactly one column,
+    /// which pushes the card's right border out of place.
+    BackSymbolNotSingleWidth(char),
+    /// The suit symbol is wider than the two columns reserved for it in the card's layout.
+    SymbolTooWide {
+        /// The form of the symbol that is too wide.
+        presentation: SymbolPresentation,
+        /// The number of columns the symbol occupies.
+        width: u16,
+    },
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SuitMatchesBackground(suit) => {
+                write!(f, "{suit} are drawn in the background color")
+            }
+            Self::BackMatchesBackground => {
+                write!(f, "the card back is drawn in the background color")
+            }
+            Self::BackSymbolNotSingleWidth(symbol) => {
+                write!(
+                    f,
+                    "the card back symbol {symbol:?} does not occupy exactly one column"
+                )
+            }
+            Self::SymbolTooWide {
+                presentation,
+                width,
+            } => write!(
+                f,
+                "the {presentation:?} suit symbol is {width} columns wide, but at most 2 fit"
+            ),
+        }
+    }
+}
+
+impl Error for CardError {}
+
+/// The side of a card that [`Card::render_clipped`] reveals.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RevealSide {
+    /// The leftmost columns are shown, as for a card sliding out to the left.
+    #[default]
+    Left,
+    /// The rightmost columns are shown, as for a card sliding out to the right.
+    Right,
+}
+
+impl Card {
+    pub const fn new(rank: Rank, suit: Suit) -> Self {
+        Self {
+            rank,
+            suit,
+            size: CardSize::Normal,
+            alignment: Alignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            theme: CardTheme::new(),
+            corner_layout: CornerLayout::SideBySide,
+            ghost: false,
+            outline_only: false,
+            ascii: false,
+            facing: Facing::Up,
+            back: None,
+            back_tinted: false,
+            reversed: false,
+            court_art: true,
+            highlighted: false,
+            highlight_style: Style::new().green(),
+            interior_fill: None,
+            rank_emphasis: Modifier::empty(),
+            bevel: None,
+            marker: None,
+            tags: 0,
+            joker: None,
+        }
+    }
+
+    /// Creates a joker card of the given color and size.
+    ///
+    /// Jokers have no rank or suit, so the card's [`rank`](Card::rank) and
+    /// [`suit`](Card::suit) are placeholders, the King of the color's [suit](JokerColor::suit),
+    /// which rendering and the description ignore. The joker is drawn in its suit's color, with
+    /// `JOKER` in the corners and a jester in the middle. Jokers are not a [`Rank`], so
+    /// [`Rank::iter`](strum::IntoEnumIterator::iter) and [`Deck::standard`](crate::Deck::standard)
+    /// never include them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, JokerColor};
+    ///
+    /// let joker = Card::joker(JokerColor::Red, CardSize::Small);
+    /// assert!(joker.is_joker());
+    /// assert_eq!(joker.describe(), "Red Joker");
+    /// ```
+    pub const fn joker(color: JokerColor, size: CardSize) -> Self {
+        let mut card = Self::new(Rank::King, color.suit()).size(size);
+        card.joker = Some(color);
+        card
+    }
+
+    /// Returns true if the card is a joker. See [`Card::joker`].
+    pub const fn is_joker(&self) -> bool {
+        self.joker.is_some()
+    }
+
+    /// Sets the size the card is rendered at.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+    /// ```
+    #[must_use]
+    pub const fn size(mut self, size: CardSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Changes the size the card is rendered at, for apps that keep a card around and resize it,
+    /// e.g. when a key is pressed.
+    ///
+    /// The card is laid out from its size each time it is rendered, so the next render uses the
+    /// new size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, Rank, Suit};
+    ///
+    /// let mut card = Card::new(Rank::Ace, Suit::Spades);
+    /// card.set_size(CardSize::Large);
+    /// assert_eq!(card, Card::new(Rank::Ace, Suit::Spades).size(CardSize::Large));
+    /// ```
+    pub const fn set_size(&mut self, size: CardSize) {
+        self.size = size;
+    }
+
+    /// Sets the colors the card is rendered with.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardTheme, Rank, Suit, SuitTheme};
+    ///
+    /// let theme = CardTheme::new().suits(SuitTheme::two_color());
+    /// let card = Card::new(Rank::Ace, Suit::Spades).theme(theme);
+    /// ```
+    #[must_use]
+    pub const fn theme(mut self, theme: CardTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets how the rank and suit are arranged in the corners of a [`CardSize::Normal`] card.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CornerLayout, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ten, Suit::Hearts).corner_layout(CornerLayout::Stacked);
+    /// ```
+    #[must_use]
+    pub const fn corner_layout(mut self, corner_layout: CornerLayout) -> Self {
+        self.corner_layout = corner_layout;
+        self
+    }
+
+    /// Sets whether the card is rendered as a ghost: a dim, dashed outline showing only the rank.
+    ///
+    /// Terminals cannot draw translucent cards, so this approximates the semi-transparent preview
+    /// that drag and drop interfaces show at a drop target. The suit symbols and face art are
+    /// skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let preview = Card::new(Rank::Nine, Suit::Clubs).ghost(true);
+    /// ```
+    #[must_use]
+    pub const fn ghost(mut self, ghost: bool) -> Self {
+        self.ghost = ghost;
+        self
+    }
+
+    /// Sets whether only the border of the card is drawn, leaving the inside blank.
+    ///
+    /// The rank, suit, pips, and art are skipped on both sides of the card, as is the
+    /// [interior fill](Card::interior_fill), while the border keeps the card's style, bevel, and
+    /// border background. This is lighter than a [`CardSlot`](crate::CardSlot) for drop targets
+    /// and loading states, and can be combined with [`Card::ghost`] to dim the outline. Compact
+    /// cards keep only their underline, and glyph and tiny cards, which have no border, are
+    /// unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let drop_target = Card::new(Rank::Ace, Suit::Spades).outline_only(true);
+    /// ```
+    #[must_use]
+    pub const fn outline_only(mut self, outline_only: bool) -> Self {
+        self.outline_only = outline_only;
+        self
+    }
+
+    /// Sets whether the card is drawn with ASCII characters only, for terminals that can't draw
+    /// box drawing characters or suit symbols.
+    ///
+    /// The border is drawn with `+`, `-`, and `|`, the suit symbols are replaced by the first
+    /// letter of the suit's name (`S`, `H`, `D`, and `C`), and other symbols, such as the pattern
+    /// on the back, are replaced by `#` or a similar ASCII character. The card keeps its size and
+    /// layout. A [`CardSize::Glyph`] card shows the rank's [symbol](Rank::as_symbol), and the
+    /// large pip of [`AcePip::Large`] is replaced by the regular one. The [marker](Card::marker)
+    /// and [interior fill](Card::interior_fill) are drawn as given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::Widget;
+    /// use tui_cards::{Card, CardSize, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades)
+    ///     .size(CardSize::Small)
+    ///     .ascii(true);
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+    /// card.render(buf.area, &mut buf);
+    /// assert_eq!(buf[(0, 0)].symbol(), "+");
+    /// assert_eq!(buf[(3, 2)].symbol(), "S");
+    /// ```
+    #[must_use]
+    pub const fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Sets which side of the card is shown.
+    ///
+    /// Face down cards render a hatched back instead of their rank and suit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Facing, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades).facing(Facing::Down);
+    /// ```
+    #[must_use]
+    pub const fn facing(mut self, facing: Facing) -> Self {
+        self.facing = facing;
+        self
+    }
+
+    /// Returns true if the card shows its back rather than its rank and suit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Facing, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades);
+    /// assert!(!card.is_face_down());
+    /// assert!(card.facing(Facing::Down).is_face_down());
+    /// ```
+    pub const fn is_face_down(&self) -> bool {
+        matches!(self.facing, Facing::Down)
+    }
+
+    /// Sets the pattern drawn on the back of the card when it is face down, in place of the
+    /// default diagonal hatch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardBack, Facing, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades)
+    ///     .facing(Facing::Down)
+    ///     .back(CardBack::solid('░'));
+    /// ```
+    #[must_use]
+    pub const fn back(mut self, back: CardBack) -> Self {
+        self.back = Some(back);
+        self
+    }
+
+    /// Sets whether the back of a face down card is drawn in its suit's color.
+    ///
+    /// This replaces the foreground of [`CardTheme::back_style`]. Backs are normally the same for
+    /// every card, so this is off by default. Tinting the backs is
+    /// useful for color coding hidden cards, e.g. while debugging a game.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Facing, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Hearts)
+    ///     .facing(Facing::Down)
+    ///     .back_tinted(true);
+    /// ```
+    #[must_use]
+    pub const fn back_tinted(mut self, back_tinted: bool) -> Self {
+        self.back_tinted = back_tinted;
+        self
+    }
+
+    /// Sets whether the card is rendered upside down.
+    ///
+    /// The face is turned half way around, so the corner indices swap places and the court art
+    /// and any pips that are not symmetric appear flipped. Most number cards look the same either
+    /// way, as on real cards. The indices themselves stay readable, and [`CardSize::Glyph`] cards
+    /// are never reversed as there is no upside down playing card character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Seven, Suit::Hearts).reversed(true);
+    /// ```
+    #[must_use]
+    pub const fn reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Sets the theme of the card to the one returned by the closure, which is given the card.
+    ///
+    /// This is handy for styling cards conditionally while mapping over them, e.g. to highlight
+    /// the trump suit, without breaking up the builder chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_cards::{Card, Deck, Suit};
+    ///
+    /// let cards: Vec<Card> = Deck::standard()
+    ///     .cards()
+    ///     .iter()
+    ///     .map(|card| {
+    ///         card.with_style_fn(|card| match card.suit {
+    ///             Suit::Hearts => card.theme.background(Color::Yellow),
+    ///             _ => card.theme,
+    ///         })
+    ///     })
+    ///     .collect();
+    /// ```
+    #[must_use]
+    pub fn with_style_fn<F: FnOnce(&Self) -> CardTheme>(mut self, f: F) -> Self {
+        self.theme = f(&self);
+        self
+    }
+
+    /// Sets whether court cards are drawn with their detailed art.
+    ///
+    /// Without the art, the middle of a Jack, Queen, or King of [`CardSize::Normal`] or
+    /// [`CardSize::Large`] shows only its letter, for a minimal look or to render many cards
+    /// cheaply. Other ranks and sizes are unaffected. Defaults to `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::King, Suit::Hearts).court_art(false);
+    /// ```
+    #[must_use]
+    pub const fn court_art(mut self, court_art: bool) -> Self {
+        self.court_art = court_art;
+        self
+    }
+
+    /// Sets whether the card is highlighted, e.g. because it has focus or is under the mouse.
+    ///
+    /// A highlighted card has [`Card::highlight_style`] applied on top of its border, leaving the
+    /// rest of the card in its usual colors. [`Hand::highlight`] highlights cards in the same way,
+    /// with the style of the hand.
+    ///
+    /// [`Hand::highlight`]: crate::Hand::highlight
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades).highlighted(true);
+    /// ```
+    #[must_use]
+    pub const fn highlighted(mut self, highlighted: bool) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    /// Sets the style applied to the border of the card when it is highlighted.
+    ///
+    /// The style is patched onto the border, separately from the card's theme, so an app can
+    /// animate a focused card, e.g. pulsing the border's brightness, by passing a different style
+    /// each frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// # let frame_count = 0u8;
+    /// let brightness = 155 + frame_count % 100;
+    /// let card = Card::new(Rank::Ace, Suit::Spades)
+    ///     .highlighted(true)
+    ///     .highlight_style(Style::new().fg(Color::Rgb(brightness, brightness, 0)));
+    /// ```
+    #[must_use]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets a symbol to fill the empty cells inside the card's border with, giving the card a
+    /// textured look.
+    ///
+    /// The style is patched onto the card's style for the filled cells, so the pips, indices, and
+    /// art are drawn as usual on top of the texture. Only boxed cards that are face up and not
+    /// ghosts are filled. By default the empty cells are left blank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Seven, Suit::Clubs).interior_fill('·', Style::new().dim());
+    /// ```
+    #[must_use]
+    pub fn interior_fill<S: Into<Style>>(mut self, symbol: char, style: S) -> Self {
+        self.interior_fill = Some((symbol, style.into()));
+        self
+    }
+
+    /// Emphasizes the rank in the corners of the card with the given modifier, e.g. to mark the
+    /// led card of a trick or a called card.
+    ///
+    /// Only the cells of the corner indices are modified, so the rest of the card keeps its usual
+    /// look. Glyph, tiny, face down, and ghost cards are not emphasized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Modifier;
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let led = Card::new(Rank::Queen, Suit::Hearts).emphasize_rank(Modifier::UNDERLINED);
+    /// ```
+    #[must_use]
+    pub const fn emphasize_rank(mut self, modifier: Modifier) -> Self {
+        self.rank_emphasis = modifier;
+        self
+    }
+
+    /// Draws the border in two colors so the card looks raised off the table: `light` on the top
+    /// and left edges, and `dark` on the bottom and right edges.
+    ///
+    /// The top right and bottom left corners belong to the top and left edges. Compact and glyph
+    /// cards have no border and are unaffected. By default the border is drawn flat in the card's
+    /// color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades).bevel(Color::White, Color::DarkGray);
+    /// ```
+    #[must_use]
+    pub const fn bevel(mut self, light: Color, dark: Color) -> Self {
+        self.bevel = Some((light, dark));
+        self
+    }
+
+    /// Sets where the card sits horizontally when it is rendered into an area wider than it, e.g.
+    /// [`Alignment::Center`] to center it in a popup.
+    ///
+    /// Defaults to [`Alignment::Left`]. When the area is narrower than the card, the card starts
+    /// at the left edge of the area and is clipped whatever the alignment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::Alignment;
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades).alignment(Alignment::Right);
+    /// ```
+    #[must_use]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets where the card sits vertically when it is rendered into an area taller than it.
+    ///
+    /// Defaults to [`VerticalAlignment::Top`]. When the area is shorter than the card, the card
+    /// starts at the top edge of the area and is clipped whatever the alignment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Alignment, VerticalAlignment};
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades)
+    ///     .alignment(Alignment::Center)
+    ///     .vertical_alignment(VerticalAlignment::Center);
+    /// ```
+    #[must_use]
+    pub const fn vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets a marker drawn in the top right corner of the card, e.g. to flag a wild card or the
+    /// card that was just drawn.
+    ///
+    /// The top right corner is opposite the corner indices, so the marker never hides the rank.
+    /// It replaces the corner of the border, or the last cell of the top line of a
+    /// [`CardSize::Compact`] card, and its style is patched onto the card's. [`CardSize::Glyph`]
+    /// cards are a single cell and have no room for a marker. Pass `None` to remove the marker.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let drawn = Card::new(Rank::Five, Suit::Clubs).marker(Some(('★', Style::new().yellow())));
+    /// ```
+    #[must_use]
+    pub const fn marker(mut self, marker: Option<(char, Style)>) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Sets the game specific tags of the card, a bitset whose meaning is up to the app, e.g. to
+    /// mark wild cards or trumps without wrapping cards in another type.
+    ///
+    /// Tags never change how a card renders. Pair them with a presentation option such as
+    /// [`Card::marker`] to show them. They are compared by `==` but ignored by
+    /// [`Card::same_card`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// const WILD: u8 = 1 << 0;
+    /// const TRUMP: u8 = 1 << 1;
+    ///
+    /// let card = Card::new(Rank::Two, Suit::Hearts).tag(WILD | TRUMP);
+    /// assert!(card.has_tag(WILD));
+    /// assert!(card.same_card(&Card::new(Rank::Two, Suit::Hearts)));
+    /// ```
+    #[must_use]
+    pub const fn tag(mut self, tags: u8) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Returns true if the card has every tag set in `tag`.
+    pub const fn has_tag(&self, tag: u8) -> bool {
+        self.tags & tag == tag
+    }
+
+    /// Sets the background color of the card's border, giving the card a matted look.
+    ///
+    /// This is a shortcut for [`CardTheme::border_background`] on the card's theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades).border_bg(Color::Gray);
+    /// ```
+    #[must_use]
+    pub const fn border_bg(mut self, color: Color) -> Self {
+        self.theme = self.theme.border_background(color);
+        self
+    }
+
+    /// Renders the card in the middle of the area, e.g. to show a single card in a popup.
+    ///
+    /// This ignores the card's [alignment](Card::alignment), as if both were set to center. When
+    /// the area is narrower or shorter than the card, the card starts at the left or top edge of
+    /// the area and is clipped like a card rendered directly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, Rank, Suit};
+    ///
+    /// # fn draw(frame: &mut ratatui::Frame) {
+    /// let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Large);
+    /// card.render_centered(frame.area(), frame.buffer_mut());
+    /// # }
+    /// ```
+    pub fn render_centered(&self, area: Rect, buf: &mut Buffer) {
+        self.alignment(Alignment::Center)
+            .vertical_alignment(VerticalAlignment::Center)
+            .render(area, buf);
+    }
+
+    /// Renders only `reveal_cols` columns of the card from the given side, starting at the left
+    /// edge of the area, e.g. for a card emerging from the deck as it is dealt.
+    ///
+    /// An animation increases `reveal_cols` from 0, which draws nothing, to the width of the card,
+    /// which draws the whole card. A wide symbol that is cut in half by the edge of the revealed
+    /// part is replaced by a blank cell in its style, so nothing is drawn past the revealed
+    /// columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use tui_cards::{Card, Rank, RevealSide, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades);
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+    /// card.render_clipped(buf.area, &mut buf, 3, RevealSide::Right);
+    /// assert_eq!(buf[(2, 0)].symbol(), "╮");
+    /// assert_eq!(buf[(3, 0)].symbol(), " ");
+    /// ```
+    pub fn render_clipped(&self, area: Rect, buf: &mut Buffer, reveal_cols: u16, side: RevealSide) {
+        let size = self.size.dimensions();
+        let reveal_cols = reveal_cols.min(size.width);
+        let mut scratch = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+        self.render(scratch.area, &mut scratch);
+        let skipped = match side {
+            RevealSide::Left => 0,
+            RevealSide::Right => size.width - reveal_cols,
+        };
+        let visible = Rect::new(area.x, area.y, reveal_cols, size.height)
+            .intersection(area)
+            .intersection(buf.area);
+        for position in visible.positions() {
+            let x = position.x - area.x + skipped;
+            let y = position.y - area.y;
+            let mut cell = scratch[(x, y)].clone();
+            let first = position.x == area.x;
+            let last = x + 1 == skipped + reveal_cols;
+            if first && x > 0 && scratch[(x - 1, y)].symbol().width() > 1 {
+                // the left half of a wide symbol is hidden
+                cell = scratch[(x - 1, y)].clone();
+                cell.set_symbol(" ");
+            } else if last && cell.symbol().width() > 1 {
+                // the right half of a wide symbol is hidden
+                cell.set_symbol(" ");
+            }
+            buf[position] = cell;
+        }
+    }
+
+    pub fn as_colored_symbol(&self) -> String {
+        if self.joker.is_some() {
+            return JESTER_PIP.to_string();
+        }
+        format!(
+            "{}{}",
+            self.rank.as_symbol(),
+            self.suit.as_four_color_symbol()
+        )
+    }
+
+    /// Returns the Unicode playing card character for this card, which [`CardSize::Glyph`] cards
+    /// are rendered as.
+    ///
+    /// The character is [`Suit::unicode_block_offset`] plus [`Rank::unicode_card_offset`], or the
+    /// joker character of a [joker](Card::joker).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// assert_eq!(Card::new(Rank::Ace, Suit::Spades).as_unicode_char(), '🂡');
+    /// assert_eq!(Card::new(Rank::Queen, Suit::Hearts).as_unicode_char(), '🂽');
+    /// ```
+    pub fn as_unicode_char(&self) -> char {
+        if let Some(color) = self.joker {
+            return color.as_unicode_char();
+        }
+        let code_point = self.suit.unicode_block_offset() + self.rank.unicode_card_offset();
+        char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Returns the Unicode playing card back character, `🂠`, which face down [`CardSize::Glyph`]
+    /// cards are rendered as.
+    ///
+    /// Every card has the same back, so this is handy for showing hidden cards in a single cell.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// assert_eq!(Card::new(Rank::Ace, Suit::Spades).as_unicode_back_char(), '🂠');
+    /// ```
+    pub const fn as_unicode_back_char(&self) -> char {
+        BACK_GLYPH
+    }
+
+    /// Returns a key that changes whenever the way the card renders changes, for apps that cache
+    /// rendered cards and skip rendering the ones that are unchanged.
+    ///
+    /// The key is a hash of every field of the card: the rank, suit, size, theme, corner layout,
+    /// facing, and each presentation option. Cards that are equal with `==` always have the same
+    /// key. Some fields only matter in combination with others, such as the highlight style of a
+    /// card that is not highlighted, so the key can change while the rendering does not, but
+    /// apart from rare hash collisions never the other way around. The key is only stable within a
+    /// single run of the program, so do not store it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades);
+    /// assert_eq!(card.render_key(), card.render_key());
+    /// assert_ne!(card.render_key(), card.ghost(true).render_key());
+    /// ```
+    pub fn render_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the rendered card as styled lines, one for each row of the card at its size, e.g.
+    /// to join several cards side by side in a single line of text.
+    ///
+    /// The lines hold the card with its theme, presentation options, highlight, and marker, and
+    /// are what rendering the card draws. Neighboring cells of the same style are merged into one
+    /// span.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, Rank, Suit};
+    ///
+    /// let lines = Card::new(Rank::Ace, Suit::Spades)
+    ///     .size(CardSize::Small)
+    ///     .to_lines();
+    /// assert_eq!(lines.len(), 5);
+    /// assert_eq!(lines[0].to_string(), "╭──────╮");
+    /// ```
+    pub fn to_lines(&self) -> Vec<Line<'static>> {
+        let height = self.size.dimensions().height;
+        let mut lines = vec![Line::default(); usize::from(height)];
+        self.draw_cells(|position, symbol, style| {
+            let Some(line) = lines.get_mut(usize::from(position.y)) else {
+                return;
+            };
+            let style = cell_style(style);
+            match line.spans.last_mut() {
+                Some(span) if span.style == style => span.content.to_mut().push_str(symbol),
+                _ => line.spans.push(Span::styled(symbol.to_string(), style)),
+            }
+        });
+        lines
+    }
+
+    /// Returns the rendered card as styled text, for showing it inside other widgets such as a
+    /// `Paragraph`, a `Block`, or a table cell.
+    ///
+    /// The text has the lines of [`Card::to_lines`]. It can also be created with `Text::from`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Block, Paragraph};
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let text = Card::new(Rank::Ace, Suit::Spades).to_text();
+    /// let paragraph = Paragraph::new(text).block(Block::bordered());
+    /// ```
+    pub fn to_text(&self) -> Text<'static> {
+        Text::from(self.to_lines())
+    }
+
+    /// Returns the symbol and style of every cell of the rendered card, row by row, for apps that
+    /// composite cards themselves or export them without a [`Buffer`].
+    ///
+    /// The grid is the size of the card, and holds exactly what rendering the card draws,
+    /// including its theme, presentation options, highlight, and marker. A wide symbol such as a
+    /// suit is reported in its first cell by its base character, without the emoji variation
+    /// selector that a `char` cannot hold, and the cell it covers is reported as a space in the
+    /// same style.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, Rank, Suit};
+    ///
+    /// let cells = Card::new(Rank::Ace, Suit::Spades)
+    ///     .size(CardSize::Small)
+    ///     .to_cells();
+    /// assert_eq!(cells.len(), 5);
+    /// assert_eq!(cells[1][1].0, 'A');
+    /// ```
+    pub fn to_cells(&self) -> Vec<Vec<(char, Style)>> {
+        let Size { width, height } = self.size.dimensions();
+        let mut rows = vec![Vec::with_capacity(usize::from(width)); usize::from(height)];
+        self.draw_cells(|position, symbol, style| {
+            let Some(row) = rows.get_mut(usize::from(position.y)) else {
+                return;
+            };
+            let style = cell_style(style);
+            row.push((symbol.chars().next().unwrap_or(' '), style));
+            row.extend(iter::repeat_n(
+                (' ', style),
+                symbol.width().saturating_sub(1),
+            ));
+        });
+        for row in &mut rows {
+            row.truncate(usize::from(width));
+        }
+        rows
+    }
+
+    /// Returns true if both cards have the same rank and suit, or are jokers of the same color.
+    ///
+    /// A joker is never the same card as a regular card, not even the King whose rank and suit it
+    /// holds as a placeholder. This ignores any presentation options, so a card is always the same
+    /// card as itself no matter how it is styled. Use `==` to compare cards including their
+    /// presentation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, JokerColor, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades);
+    /// assert!(card.same_card(&Card::new(Rank::Ace, Suit::Spades)));
+    /// assert!(!card.same_card(&Card::new(Rank::Ace, Suit::Hearts)));
+    ///
+    /// let joker = Card::joker(JokerColor::Red, CardSize::Normal);
+    /// assert!(!joker.same_card(&Card::new(Rank::King, Suit::Hearts)));
+    /// ```
+    pub fn same_card(&self, other: &Self) -> bool {
+        self.rank == other.rank && self.suit == other.suit && self.joker == other.joker
+    }
+
+    /// Returns a full human readable description of the card, e.g. "Ace of Spades".
+    ///
+    /// Unlike [`Card::as_colored_symbol`], which is compact and relies on the suit glyph, this is
+    /// intended for screen readers, alt-text, and logging where the card must be understood without
+    /// seeing it. Face down cards are described as "face-down card" so the description does not
+    /// reveal them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Queen, Suit::Hearts);
+    /// assert_eq!(card.describe(), "Queen of Hearts");
+    /// ```
+    pub fn describe(&self) -> String {
+        if self.facing == Facing::Down {
+            return "face-down card".to_string();
+        }
+        if let Some(color) = self.joker {
+            return format!("{color} Joker");
+        }
+        format!("{} of {}", self.rank, self.suit)
+    }
+
+    /// Returns the same description as [`Card::describe`] followed by the name of the color the
+    /// suit is rendered in, e.g. "Ace of Spades (black)". Colors without a name, such as those of
+    /// [`SuitTheme::colorblind_friendly`], are named after their closest hue.
+    ///
+    /// [`SuitTheme::colorblind_friendly`]: crate::SuitTheme::colorblind_friendly
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Two, Suit::Diamonds);
+    /// assert_eq!(card.describe_with_color(), "Two of Diamonds (blue)");
+    /// ```
+    pub fn describe_with_color(&self) -> String {
+        if self.facing == Facing::Down {
+            // the color of a hidden card's suit would give the card away
+            return self.describe();
+        }
+        format!("{} ({})", self.describe(), color_name(self.color()))
+    }
+}
+
+impl Card {
+    /// Checks that the card's theme lets it render legibly, so apps with custom themes can fail
+    /// fast rather than draw unreadable cards.
+    ///
+    /// This checks that the suit and the back of the card are not drawn in the same color as the
+    /// background behind them, that the symbols of the back are one column wide, and that the
+    /// corner and pip suit symbols fit the layout. A suit color that matches the background is
+    /// reported even though the card is drawn with a contrasting color instead (see
+    /// [`SuitTheme::resolved_for`]), as the card would not look the way the theme intends.
+    ///
+    /// [`SuitTheme::resolved_for`]: crate::SuitTheme::resolved_for
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`CardError`] found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_cards::{Card, CardError, CardTheme, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades);
+    /// assert_eq!(card.validate(), Ok(()));
+    ///
+    /// let card = card.theme(CardTheme::new().background(Color::Black));
+    /// assert_eq!(
+    ///     card.validate(),
+    ///     Err(CardError::SuitMatchesBackground(Suit::Spades))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), CardError> {
+        if self.theme.suits.color(self.suit) == self.theme.background {
+            return Err(CardError::SuitMatchesBackground(self.suit));
+        }
+        let back = Style::new()
+            .fg(self.color())
+            .bg(self.theme.background)
+            .patch(self.theme.back_style)
+            .patch(self.back.unwrap_or_default().back_style());
+        if !self.back_tinted && back.fg == back.bg {
+            return Err(CardError::BackMatchesBackground);
+        }
+        if let Some(symbol) = self.back.and_then(|back| back.wide_symbol()) {
+            return Err(CardError::BackSymbolNotSingleWidth(symbol));
+        }
+        for presentation in [self.theme.corner_suit_symbol, self.theme.pip_suit_symbol] {
+            let width = self.suit.symbol_width(presentation);
+            if width > 2 {
+                return Err(CardError::SymbolTooWide {
+                    presentation,
+                    width,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the color the suit is drawn in: its color in the card's theme, resolved against the
+    /// card's background for legibility.
+    pub(crate) fn color(&self) -> Color {
+        self.theme
+            .suits
+            .resolved_for(self.theme.background)
+            .color(self.suit)
+    }
+
+    /// Returns the label printed in the corners of the card: the rank, or `JOKER` for jokers.
+    fn index(&self) -> &'static str {
+        if self.joker.is_some() {
+            JOKER_LABEL
+        } else {
+            self.rank.short_name()
+        }
+    }
+
+    /// Returns the lines of the ghost outline: a dashed border with the rank in two corners, laid
+    /// out like the regular template for the card's size.
+    fn ghost_lines(self) -> Vec<String> {
+        let Size { width, height } = self.size.dimensions();
+        if self.size == CardSize::Compact {
+            let index = self.index();
+            let width = usize::from(width);
+            return vec![format!("{index:<width$}"), "╌".repeat(width)];
+        }
+        let inner = usize::from(width.saturating_sub(2));
+        let index = self.index();
+        let padding = usize::from(
+            matches!(self.size, CardSize::Normal | CardSize::Large) && self.rank != Rank::Ten,
+        );
+        let fill = inner.saturating_sub(padding + index.len());
+        let last = usize::from(height).saturating_sub(1);
+        (0..=last)
+            .map(|row| match row {
+                0 => format!("╭{}╮", "╌".repeat(inner)),
+                row if row == last => format!("╰{}╯", "╌".repeat(inner)),
+                1 => format!("╎{}{index}{}╎", " ".repeat(padding), " ".repeat(fill)),
+                row if row == last - 1 => {
+                    format!("╎{}{index}{}╎", " ".repeat(fill), " ".repeat(padding))
+                }
+                _ => format!("╎{}╎", " ".repeat(inner)),
+            })
+            .collect()
+    }
+
+    /// Returns the part of the area the card occupies, placed by its alignment.
+    fn aligned_area(&self, area: Rect) -> Rect {
+        let size = self.size.dimensions();
+        let spare_width = area.width.saturating_sub(size.width);
+        let spare_height = area.height.saturating_sub(size.height);
+        let x = match self.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => spare_width / 2,
+            Alignment::Right => spare_width,
+        };
+        let y = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => spare_height / 2,
+            VerticalAlignment::Bottom => spare_height,
+        };
+        Rect::new(
+            area.x.saturating_add(x),
+            area.y.saturating_add(y),
+            size.width.min(area.width),
+            size.height.min(area.height),
+        )
+    }
+
+    /// Calls `draw` with the position, symbol, and style of every cell of the card, row by row
+    /// from the top left, including everything drawn over the symbols of the card: its interior
+    /// fill, rank emphasis, border colors, large ace pip, highlight, and marker.
+    ///
+    /// This is the one place that decides how a card looks. Rendering writes the cells into the
+    /// buffer, and [`Card::to_lines`] and [`Card::to_cells`] collect them. A wide symbol is passed
+    /// once, at its first cell. The symbols of a face in [`DEFAULT_FACES`], a joker, or a back are
+    /// looked up rather than built, so such cards are drawn without allocating (checked by
+    /// `tests/allocations.rs`).
+    fn draw_cells(&self, mut draw: impl FnMut(Position, &str, Style)) {
+        let draw = &mut draw;
+        let mut style = Style::new().fg(self.color()).bg(self.theme.background);
+        if self.ghost {
+            style = style.dim();
+        }
+        if self.outline_only && !matches!(self.size, CardSize::Glyph | CardSize::Tiny) {
+            let lines = outline_lines(self.size);
+            self.draw_lines(lines.iter().map(String::as_str), style, Part::Framed, draw);
+            return;
+        }
+        if self.facing == Facing::Down {
+            let mut back_style = style
+                .patch(self.theme.back_style)
+                .patch(self.back.unwrap_or_default().back_style());
+            if self.back_tinted {
+                back_style = back_style.fg(self.color());
+            }
+            self.draw_back(back_style, draw);
+            return;
+        }
+        if self.size == CardSize::Glyph {
+            let glyph = if self.ascii {
+                self.joker.map_or(self.rank.as_symbol(), |_| '*')
+            } else {
+                self.as_unicode_char()
+            };
+            self.draw_lines([&*glyph.encode_utf8(&mut [0; 4])], style, Part::Plain, draw);
+            return;
+        }
+        if self.size == CardSize::Tiny {
+            self.draw_tiny(style, draw);
+            return;
+        }
+        if self.ghost {
+            let lines = self.ghost_lines();
+            self.draw_lines(lines.iter().map(String::as_str), style, Part::Plain, draw);
+            return;
+        }
+        if self.joker.is_some() {
+            self.draw_lines(joker_face(self.size).lines(), style, Part::Framed, draw);
+            return;
+        }
+        let key = self.face_key();
+        let built;
+        let face = match DEFAULT_FACES.get(&key) {
+            Some(face) => face,
+            None => {
+                built = key.build();
+                &built
+            }
+        };
+        self.draw_lines(face.content.lines(), style, Part::Face(face), draw);
+    }
+
+    /// Draws the back of the card in place of its face.
+    fn draw_back(&self, style: Style, draw: &mut impl FnMut(Position, &str, Style)) {
+        if self.size == CardSize::Glyph {
+            let glyph = if self.ascii {
+                '#'
+            } else {
+                self.as_unicode_back_char()
+            };
+            self.draw_lines([&*glyph.encode_utf8(&mut [0; 4])], style, Part::Plain, draw);
+            return;
+        }
+        let back = self.back.unwrap_or_default();
+        let Size { width, height } = self.size.dimensions();
+        for position in Rect::new(0, 0, width, height).positions() {
+            let symbol = back_symbol(self.size, &back, position.x, position.y);
+            let mut bytes = [0; 4];
+            let symbol = symbol.encode_utf8(&mut bytes);
+            self.draw_symbol(position, symbol, style, Part::Framed, false, draw);
+        }
+    }
+
+    /// Draws the single row of a [`CardSize::Tiny`] card: the rank followed by the text
+    /// presentation of the suit symbol, or the jester for jokers, padded to the card's width.
+    fn draw_tiny(&self, style: Style, draw: &mut impl FnMut(Position, &str, Style)) {
+        let (rank, suit) = if self.joker.is_some() {
+            ("", JESTER_PIP)
+        } else {
+            (self.rank.short_name(), self.suit.as_text_symbol())
+        };
+        let mut x = 0u16;
+        for symbol in symbols(rank).chain(symbols(suit)) {
+            self.draw_symbol(Position::new(x, 0), symbol, style, Part::Plain, false, draw);
+            x = x.saturating_add(symbol.width() as u16);
+        }
+        for x in x..CardSize::Tiny.dimensions().width {
+            self.draw_symbol(Position::new(x, 0), " ", style, Part::Plain, false, draw);
+        }
+    }
+
+    /// Draws each line on its own row of the card, from the top.
+    fn draw_lines<'a>(
+        &self,
+        lines: impl IntoIterator<Item = &'a str>,
+        style: Style,
+        part: Part<'_>,
+        draw: &mut impl FnMut(Position, &str, Style),
+    ) {
+        // the template of a face has a single column character for each cell, so it tells which
+        // cells the face leaves blank
+        let mut template = match part {
+            Part::Face(face) => Some(face.template.lines()),
+            _ => None,
+        };
+        for (y, line) in (0..).zip(lines) {
+            let mut template_row = template.as_mut().and_then(Iterator::next).map(str::chars);
+            let mut x = 0u16;
+            for symbol in symbols(line) {
+                let width = symbol.width() as u16;
+                let blank = template_row.as_mut().is_some_and(|row| {
+                    let blank = row.next() == Some(' ');
+                    // the template characters covered by a wide symbol are skipped along with it
+                    row.take(usize::from(width).saturating_sub(1))
+                        .for_each(drop);
+                    blank
+                });
+                self.draw_symbol(Position::new(x, y), symbol, style, part, blank, draw);
+                x = x.saturating_add(width);
+            }
+        }
+    }
+
+    /// Draws a symbol whose first cell is at the given position of the card, converted to ASCII
+    /// if the card is drawn with ASCII characters only.
+    ///
+    /// `blank` is whether the face's template leaves the cell blank, so that the interior fill
+    /// is drawn there.
+    fn draw_symbol(
+        &self,
+        position: Position,
+        symbol: &str,
+        style: Style,
+        part: Part<'_>,
+        blank: bool,
+        draw: &mut impl FnMut(Position, &str, Style),
+    ) {
+        let width = symbol.width() as u16;
+        if self.ascii {
+            let (first, rest) = ascii_symbol(symbol);
+            for (offset, ascii) in (0..width).zip(iter::once(first).chain(iter::repeat(rest))) {
+                let position = Position::new(position.x.saturating_add(offset), position.y);
+                let mut bytes = [0; 4];
+                let ascii = ascii.encode_utf8(&mut bytes);
+                self.draw_cell(position, ascii, style, part, blank, draw);
+            }
+            return;
+        }
+        let covered_by_pip = matches!(part, Part::Face(_))
+            && self.has_large_ace_pip()
+            && (0..width).any(|offset| {
+                self.ace_pip_pixel(position.x.saturating_add(offset), position.y)
+                    .is_some()
+            });
+        if width > 1 && covered_by_pip {
+            // the pip is drawn over each cell of the symbol separately
+            for offset in 0..width {
+                let position = Position::new(position.x.saturating_add(offset), position.y);
+                self.draw_cell(position, " ", style, part, blank, draw);
+            }
+            return;
+        }
+        self.draw_cell(position, symbol, style, part, blank, draw);
+    }
+
+    /// Draws a symbol at the given position of the card, with the symbols and styles that are
+    /// drawn over it in turn: the interior fill, rank emphasis, border background, bevel, large
+    /// ace pip, highlight, and marker.
+    fn draw_cell(
+        &self,
+        position: Position,
+        symbol: &str,
+        mut style: Style,
+        part: Part<'_>,
+        blank: bool,
+        draw: &mut impl FnMut(Position, &str, Style),
+    ) {
+        let Size { width, height } = self.size.dimensions();
+        let Position { x, y } = position;
+        let on_border = x == 0 || y == 0 || x + 1 == width || y + 1 == height;
+        let (mut fill, mut marker) = ([0; 4], [0; 4]);
+        let mut symbol = symbol;
+        if let Part::Face(face) = part {
+            // compact cards have no box, so there is no interior to fill
+            if let Some((fill_symbol, fill_style)) = self
+                .interior_fill
+                .filter(|_| blank && !on_border && self.size != CardSize::Compact)
+            {
+                symbol = fill_symbol.encode_utf8(&mut fill);
+                style = style.patch(fill_style);
+            }
+            if !self.rank_emphasis.is_empty() && face.index_cells.contains(&position) {
+                style = style.add_modifier(self.rank_emphasis);
+            }
+        }
+        if matches!(part, Part::Framed | Part::Face(_)) && on_border {
+            // compact cards have no box, so there is no border to color
+            if let Some(color) = self
+                .theme
+                .border_background
+                .filter(|_| self.size != CardSize::Compact)
+            {
+                style = style.bg(color);
+            }
+            if let Some((light, dark)) = self.bevel.filter(|_| {
+                !matches!(
+                    self.size,
+                    CardSize::Glyph | CardSize::Tiny | CardSize::Compact
+                )
+            }) {
+                style = style.fg(if x == 0 || y == 0 { light } else { dark });
+            }
+        }
+        if matches!(part, Part::Face(_)) && self.has_large_ace_pip() {
+            if let Some(filled) = self.ace_pip_pixel(x, y) {
+                // the pip replaces the regular suit symbol in the middle of the card
+                if filled {
+                    symbol = "█";
+                }
+                style = style.fg(self.color()).bg(self.theme.background);
+            }
+        }
+        let highlighted = match self.size {
+            CardSize::Glyph | CardSize::Tiny => true,
+            CardSize::Compact => y + 1 == height,
+            _ => on_border,
+        };
+        if self.highlighted && highlighted {
+            style = style.patch(self.highlight_style);
+        }
+        if let Some((marker_symbol, marker_style)) = self
+            .marker
+            .filter(|_| !matches!(self.size, CardSize::Glyph | CardSize::Tiny))
+            .filter(|_| x + 1 == width && y == 0)
+        {
+            symbol = marker_symbol.encode_utf8(&mut marker);
+            style = style.patch(marker_style);
+        }
+        draw(position, symbol, style);
+    }
+
+    /// Returns true if an ace is drawn with the large pip of [`AcePip::Large`] in its middle.
+    fn has_large_ace_pip(&self) -> bool {
+        self.rank == Rank::Ace
+            && !self.ascii
+            && self.theme.ace_pip == AcePip::Large
+            && matches!(self.size, CardSize::Normal | CardSize::Large)
+    }
+
+    /// Returns the options that decide the symbols on the face of the card.
+    fn face_key(&self) -> FaceKey {
+        FaceKey {
+            rank: self.rank,
+            suit: self.suit,
+            size: self.size,
+            court_art: self.court_art,
+            stacked: self.size == CardSize::Normal && self.corner_layout == CornerLayout::Stacked,
+            pip_scale: self.theme.pip_scale,
+            reversed: self.reversed,
+            corner_suit_symbol: self.theme.corner_suit_symbol,
+            pip_suit_symbol: self.theme.pip_suit_symbol,
+        }
+    }
+}
+
+impl Widget for &Card {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let area = self.aligned_area(area).intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        self.draw_cells(|position, symbol, style| {
+            let width = symbol.width() as u16;
+            // a symbol that does not fit is left out whole, as when rendering a span
+            if position.y >= area.height || position.x.saturating_add(width) > area.width {
+                return;
+            }
+            let x = area.x + position.x;
+            let y = area.y + position.y;
+            buf[(x, y)].set_symbol(symbol).set_style(style);
+            // the cells hidden behind a wide symbol are reset, as when rendering a span
+            for hidden in 1..width {
+                buf[(x + hidden, y)].reset();
+            }
+        });
+    }
+}
+
+/// Converts the card into styled text, for showing it inside other widgets such as a `Paragraph`
+/// or a table cell.
+///
+/// The text has one line for each row of the card at its size, with the same symbols and colors
+/// as rendering the card directly. This is the same as [`Card::to_text`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::text::Text;
+/// use ratatui::widgets::Paragraph;
+/// use tui_cards::{Card, CardSize, Rank, Suit};
+///
+/// let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+/// let text = Text::from(&card);
+/// assert_eq!(text.lines.len(), 5);
+/// let paragraph = Paragraph::new(text);
+/// ```
+impl From<&Card> for Text<'static> {
+    fn from(card: &Card) -> Self {
+        card.to_text()
+    }
+}
+
+impl From<Card> for Text<'static> {
+    fn from(card: Card) -> Self {
+        Self::from(&card)
+    }
+}
+
+/// Formats the card compactly as its rank followed by its suit symbol, e.g. `A♠` or `10♥`.
+///
+/// Cards drawn with [ASCII characters only](Card::ascii), and the alternate form `{:#}`, use the
+/// suit's [letter](Suit::as_letter) instead, e.g. `AS`. Jokers are written as `Red Joker` or
+/// `Black Joker`. Both forms parse back into the card with [`str::parse`]. For a description in
+/// words, such as `Ace of Spades`, see [`Card::describe`].
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{Card, Rank, Suit};
+///
+/// let card = Card::new(Rank::Ace, Suit::Spades);
+/// assert_eq!(card.to_string(), "A♠");
+/// assert_eq!(format!("{card:#}"), "AS");
+/// assert_eq!(card.to_string().parse(), Ok(card));
+/// ```
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(color) = self.joker {
+            return write!(f, "{color} Joker");
+        }
+        let rank = self.rank.short_name();
+        if self.ascii || f.alternate() {
+            write!(f, "{rank}{}", self.suit.as_letter())
+        } else {
+            write!(f, "{rank}{}", self.suit.as_symbol())
+        }
+    }
+}
+
+/// The faces of cards with the default layout options, built once on first use.
+///
+/// Most cards are drawn with the default options, so rendering them only looks up their face
+/// here rather than substituting the template every frame. The faces hold only symbols, as the
+/// colors are applied when the card is rendered.
+static DEFAULT_FACES: LazyLock<HashMap<FaceKey, Face>> = LazyLock::new(|| {
+    let sizes = [
+        CardSize::Compact,
+        CardSize::Small,
+        CardSize::Normal,
+        CardSize::Large,
+    ];
+    Rank::iter()
+        .cartesian_product(Suit::iter())
+        .cartesian_product(sizes)
+        .map(|((rank, suit), size)| {
+            let key = Card::new(rank, suit).size(size).face_key();
+            (key, key.build())
+        })
+        .collect()
+});
+
+/// The options that decide the symbols on the face of a card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct FaceKey {
+    rank: Rank,
+    suit: Suit,
+    size: CardSize,
+    court_art: bool,
+    stacked: bool,
+    pip_scale: PipScale,
+    reversed: bool,
+    corner_suit_symbol: SymbolPresentation,
+    pip_suit_symbol: SymbolPresentation,
+}
+
+/// The symbols on the face of a card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Face {
+    /// The template the face was built from, with `cc` and `xx` marking the suit symbols.
+    template: String,
+    /// The template with the suit symbols filled in.
+    content: String,
+    /// The positions of the cells of the corner indices, which rank emphasis is applied to.
+    index_cells: Vec<Position>,
+}
+
+/// What the symbols of a card being drawn are part of, which decides what is drawn over them.
+#[derive(Debug, Clone, Copy)]
+enum Part<'a> {
+    /// Symbols with nothing drawn over them but the highlight and marker, e.g. a ghost outline.
+    Plain,
+    /// A card with a border, which the border background and bevel are drawn over.
+    Framed,
+    /// The face of a card, which also has its interior fill, rank emphasis, and ace pip.
+    Face(&'a Face),
+}
+
+impl FaceKey {
+    fn build(&self) -> Face {
+        let mut template = self.rank.template(self.size).to_string();
+        if self.rank.is_court()
+            && !self.court_art
+            && matches!(self.size, CardSize::Normal | CardSize::Large)
+        {
+            template = remove_court_art(&template, self.rank);
+        }
+        if self.stacked {
+            template = stack_corners(&template, self.rank);
+        }
+        template = mark_corner_symbols(&template, self.rank, self.size, self.stacked);
+        if self.size == CardSize::Large && self.pip_scale == PipScale::Double {
+            // the pips of large cards always have a space either side to grow into
+            template = template.replace(" xx ", "xxxx");
+        }
+        if self.reversed {
+            template = turn_upside_down(&template);
+        }
+        let content = template
+            .replace("cc", &self.suit.template_symbol(self.corner_suit_symbol))
+            .replace("xx", &self.suit.template_symbol(self.pip_suit_symbol));
+        let index_cells = index_cells(&template, self.rank);
+        Face {
+            template,
+            content,
+            index_cells,
+        }
+    }
+}
+
+/// Returns the lines of a card of the given size with nothing inside its border.
+fn outline_lines(size: CardSize) -> Vec<String> {
+    let Size { width, height } = size.dimensions();
+    let width = usize::from(width);
+    if size == CardSize::Compact {
+        return vec![" ".repeat(width), "─".repeat(width)];
+    }
+    let inner = width.saturating_sub(2);
+    let last = usize::from(height).saturating_sub(1);
+    (0..=last)
+        .map(|row| match row {
+            0 => format!("╭{}╮", "─".repeat(inner)),
+            row if row == last => format!("╰{}╯", "─".repeat(inner)),
+            _ => format!("│{}│", " ".repeat(inner)),
+        })
+        .collect()
+}
+
+/// Replaces the art of a court card template with the rank's letter in the middle of the card.
+///
+/// The art is drawn with the letter itself, so every letter between the corner index rows is
+/// removed.
+fn remove_court_art(template: &str, rank: Rank) -> String {
+    let letter = rank.as_symbol();
+    let mut lines: Vec<Vec<char>> = template
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    let last = lines.len().saturating_sub(2);
+    for line in lines.iter_mut().take(last).skip(2) {
+        for cell in line.iter_mut().filter(|cell| **cell == letter) {
+            *cell = ' ';
+        }
+    }
+    let middle = lines.len() / 2;
+    if let Some(line) = lines.get_mut(middle) {
+        let center = line.len() / 2;
+        line[center] = letter;
+    }
+    lines
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Turns a template half way around, reversing the order of its lines and of the cells in each
+/// line.
+///
+/// Suit symbols (`xx` and `cc`) and the two digit index of the Ten are kept together so they still
+/// read correctly, and the corners of the border are swapped to match.
+fn turn_upside_down(template: &str) -> String {
+    template
+        .lines()
+        .rev()
+        .map(|line| {
+            let mut cells = Vec::new();
+            let mut rest = line;
+            while let Some(ch) = rest.chars().next() {
+                let len = ["xx", "cc", "10"]
+                    .into_iter()
+                    .find(|token| rest.starts_with(token))
+                    .map_or(ch.len_utf8(), str::len);
+                let (cell, remainder) = rest.split_at(len);
+                cells.push(match cell {
+                    "╭" => "╯",
+                    "╮" => "╰",
+                    "╰" => "╮",
+                    "╯" => "╭",
+                    "├" => "┤",
+                    "┤" => "├",
+                    cell => cell,
+                });
+                rest = remainder;
+            }
+            cells.into_iter().rev().collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders each line on its own row of the area, from the top, until either runs out.
+pub(crate) fn render_lines<'a, I>(lines: I, area: Rect, buf: &mut Buffer, style: Style)
+where
+    I: IntoIterator,
+    I::Item: Into<Cow<'a, str>>,
+{
+    for (line, row) in zip(lines, area.rows()) {
+        let line = line.into();
+        // a span only stops at the right of its area by comparing saturated positions, which
+        // writes past the buffer when the area ends at the largest column, so clip it first
+        let clipped = clip_line(&line, row.width);
+        Span::styled(clipped, style).render(row, buf);
+    }
+}
+
+/// Splits a line into the symbols of its cells: each character together with the zero width
+/// characters after it, such as the variation selector of a suit symbol.
+pub(crate) fn symbols(line: &str) -> impl Iterator<Item = &str> {
+    let mut rest = line;
+    iter::from_fn(move || {
+        let mut chars = rest.char_indices();
+        chars.next()?;
+        let end = chars
+            .find(|(_, c)| c.width() != Some(0))
+            .map_or(rest.len(), |(index, _)| index);
+        let (symbol, remainder) = rest.split_at(end);
+        rest = remainder;
+        Some(symbol)
+    })
+}
+
+/// Returns the style a cell of an empty buffer has once the given style is set on it, leaving out
+/// the reset underline color the cell holds, which a span has no need for.
+fn cell_style(style: Style) -> Style {
+    Style::new()
+        .fg(style.fg.unwrap_or(Color::Reset))
+        .bg(style.bg.unwrap_or(Color::Reset))
+        .add_modifier(style.add_modifier - style.sub_modifier)
+}
+
+/// Returns the longest start of the line that fits in the given number of columns.
+fn clip_line(line: &str, width: u16) -> &str {
+    let width = usize::from(width);
+    if line.width() <= width {
+        return line;
+    }
+    let end = line
+        .char_indices()
+        .map(|(index, _)| index)
+        .rev()
+        // never cut a symbol off from the variation selector after it
+        .filter(|&end| {
+            line[end..]
+                .chars()
+                .next()
+                .is_none_or(|c| c.width() != Some(0))
+        })
+        .find(|&end| line[..end].width() <= width)
+        .unwrap_or(0);
+    &line[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use ratatui_core::buffer::Cell;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::SuitTheme;
+
+    /// Returns the positions of the cells on the edge of the given area.
+    fn border_positions(area: Rect) -> impl Iterator<Item = Position> {
+        area.positions().filter(move |position| {
+            position.x == area.left()
+                || position.x == area.right() - 1
+                || position.y == area.top()
+                || position.y == area.bottom() - 1
+        })
+    }
+
+    fn all_cards() -> impl Iterator<Item = Card> {
+        Rank::iter()
+            .cartesian_product(Suit::iter())
+            .cartesian_product(CardSize::iter())
+            .cartesian_product([false, true])
+            .map(|(((rank, suit), size), ghost)| Card::new(rank, suit).size(size).ghost(ghost))
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+        for card in all_cards() {
+            card.render(Rect::new(5, 5, 0, 3), &mut buf);
+            card.render(Rect::new(5, 5, 3, 0), &mut buf);
+        }
+        assert_eq!(buf, Buffer::empty(buf.area));
+    }
+
+    #[test]
+    fn render_at_huge_offsets() {
+        let mut buf = Buffer::empty(Rect::new(u16::MAX - 40, u16::MAX - 20, 20, 10));
+        for card in all_cards() {
+            card.render(Rect::new(u16::MAX - 35, u16::MAX - 15, 30, 30), &mut buf);
+            card.render(Rect::new(u16::MAX - 5, u16::MAX - 3, 30, 30), &mut buf);
+            card.render(Rect::new(0, 0, 30, 30), &mut buf);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn render_never_panics_or_writes_outside_area(
+            at_edge: bool,
+            x in 0u16..60,
+            y in 0u16..40,
+            width in 0u16..40,
+            height in 0u16..30,
+            size in prop::sample::select(CardSize::iter().collect_vec()),
+            facing in prop::sample::select(vec![Facing::Up, Facing::Down]),
+            joker: bool,
+            ghost: bool,
+        ) {
+            // the buffer either lies near the origin or ends at the largest coordinates
+            let origin = if at_edge {
+                Position::new(u16::MAX - 30, u16::MAX - 20)
+            } else {
+                Position::new(10, 5)
+            };
+            let buf_area = Rect::new(origin.x, origin.y, 30, 20);
+            let area = Rect::new(
+                (origin.x - 10).saturating_add(x),
+                (origin.y - 5).saturating_add(y),
+                width,
+                height,
+            );
+            let cards = if joker {
+                [JokerColor::Red, JokerColor::Black]
+                    .map(|color| Card::joker(color, size))
+                    .to_vec()
+            } else {
+                Rank::iter()
+                    .cartesian_product(Suit::iter())
+                    .map(|(rank, suit)| Card::new(rank, suit).size(size))
+                    .collect_vec()
+            };
+            for card in cards {
+                let card = card.facing(facing).ghost(ghost);
+                let mut buf = Buffer::empty(buf_area);
+                card.render(area, &mut buf);
+                for (index, cell) in buf.content.iter().enumerate() {
+                    let position = buf.pos_of(index);
+                    if !area.contains(position.into()) {
+                        prop_assert_eq!(cell, &Cell::EMPTY);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clip_keeps_wide_symbols_whole() {
+        assert_eq!(clip_line("│A♠️ │", 3), "│A");
+        assert_eq!(clip_line("│A♠️ │", 4), "│A♠️");
+    }
+
+    #[test]
+    fn symbols_keep_selectors_with_their_character() {
+        assert_eq!(
+            symbols("│A♠️ ♥︎🃏").collect_vec(),
+            ["│", "A", "♠️", " ", "♥︎", "🃏"]
+        );
+        assert_eq!(symbols("").count(), 0);
+    }
+
+    #[test]
+    fn render_at_the_largest_coordinates() {
+        for card in all_cards() {
+            let mut buf = Buffer::empty(Rect::new(u16::MAX - 5, u16::MAX - 5, 5, 5));
+            card.render(buf.area, &mut buf);
+            card.facing(Facing::Down).render(buf.area, &mut buf);
+        }
+    }
+
+    #[test]
+    fn render_into_area_smaller_than_card() {
+        let card = Card::new(Rank::Ten, Suit::Spades);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        let area = Rect::new(2, 1, 5, 3);
+        card.render(area, &mut buf);
+        for position in buf.area.positions() {
+            if !area.contains(position) {
+                assert_eq!(buf[position], Cell::EMPTY, "{position:?}");
+            }
+        }
+        // the top left corner of the card is drawn
+        assert_eq!(buf[(2, 1)].symbol(), "╭");
+        assert_eq!(buf[(6, 1)].symbol(), "─");
+        assert_eq!(buf[(2, 2)].symbol(), "│");
+        assert_eq!(buf[(3, 2)].symbol(), "1");
+        assert_eq!(buf[(4, 2)].symbol(), "0");
+        assert_eq!(buf[(2, 3)].symbol(), "│");
+    }
+
+    #[test]
+    fn render_small() {
+        let card = Card::new(Rank::Ten, Suit::Spades).size(CardSize::Small);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│10    │", "│  ♠️  │", "│    10│", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().black().on_white());
+        // the cell hidden by the wide suit symbol is skipped when rendering
+        expected[(4, 2)].set_style(Style::reset());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_stacked_corners() {
+        let card = Card::new(Rank::Queen, Suit::Clubs).corner_layout(CornerLayout::Stacked);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        card.render(buf.area, &mut buf);
+        assert_eq!(buf[(2, 1)].symbol(), "Q");
+        assert_eq!(buf[(3, 1)].symbol(), " ");
+        assert_eq!(buf[(1, 2)].symbol(), Suit::Clubs.as_four_color_symbol());
+        assert_eq!(buf[(11, 6)].symbol(), Suit::Clubs.as_four_color_symbol());
+        assert_eq!(buf[(11, 7)].symbol(), "Q");
+        assert_eq!(buf[(9, 7)].symbol(), " ");
+    }
+
+    #[test]
+    fn stacked_corners_ignored_for_small() {
+        let card = Card::new(Rank::Ten, Suit::Spades).size(CardSize::Small);
+        let mut side_by_side = Buffer::empty(Rect::new(0, 0, 8, 5));
+        let mut stacked = side_by_side.clone();
+        card.render(side_by_side.area, &mut side_by_side);
+        card.corner_layout(CornerLayout::Stacked)
+            .render(stacked.area, &mut stacked);
+        assert_eq!(stacked, side_by_side);
+    }
+
+    #[test]
+    fn wide_suit_symbols_keep_right_border() {
+        let presentations =
+            SymbolPresentation::iter().cartesian_product(SymbolPresentation::iter());
+        for ((rank, suit), (corner, pip)) in Rank::iter()
+            .cartesian_product(Suit::iter())
+            .cartesian_product(presentations)
+        {
+            let theme = CardTheme::new()
+                .corner_suit_symbol(corner)
+                .pip_suit_symbol(pip);
+            let card = Card::new(rank, suit).theme(theme);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+            card.render(buf.area, &mut buf);
+            for y in 1..8 {
+                assert_eq!(buf[(13, y)].symbol(), "│", "{card:?} row {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn render_ghost() {
+        let card = Card::new(Rank::Ten, Suit::Hearts)
+            .size(CardSize::Small)
+            .ghost(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭╌╌╌╌╌╌╮", "╎10    ╎", "╎      ╎", "╎    10╎", "╰╌╌╌╌╌╌╯"]);
+        expected.set_style(expected.area, Style::new().red().on_white().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_outline_only() {
+        let card = Card::new(Rank::Queen, Suit::Hearts)
+            .size(CardSize::Small)
+            .interior_fill('·', Style::new().gray())
+            .outline_only(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│      │", "│      │", "│      │", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().red().on_white());
+        assert_eq!(buf, expected);
+        let mut back = Buffer::empty(buf.area);
+        card.facing(Facing::Down).render(back.area, &mut back);
+        assert_eq!(back, buf);
+    }
+
+    #[test]
+    fn outline_only_lines_match_dimensions() {
+        for size in
+            CardSize::iter().filter(|size| !matches!(size, CardSize::Glyph | CardSize::Tiny))
+        {
+            let Size { width, height } = size.dimensions();
+            let lines = outline_lines(size);
+            assert_eq!(lines.len(), usize::from(height), "{size:?}");
+            for line in lines {
+                assert_eq!(line.width(), usize::from(width), "{size:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn render_normal_ghost() {
+        let card = Card::new(Rank::King, Suit::Spades).ghost(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        card.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "╭╌╌╌╌╌╌╌╌╌╌╌╌╮",
+            "╎ K          ╎",
+            "╎            ╎",
+            "╎            ╎",
+            "╎            ╎",
+            "╎            ╎",
+            "╎            ╎",
+            "╎          K ╎",
+            "╰╌╌╌╌╌╌╌╌╌╌╌╌╯",
+        ]);
+        expected.set_style(expected.area, Style::new().black().on_white().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_compact() {
+        let card = Card::new(Rank::Ten, Suit::Hearts).size(CardSize::Compact);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        card.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["10♥️    ", "────────"]);
+        expected.set_style(expected.area, Style::new().red().on_white());
+        expected[(3, 0)].set_style(Style::reset());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_compact_ghost() {
+        let card = Card::new(Rank::Queen, Suit::Spades)
+            .size(CardSize::Compact)
+            .ghost(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        card.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["Q       ", "╌╌╌╌╌╌╌╌"]);
+        expected.set_style(expected.area, Style::new().black().on_white().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_face_down() {
+        let card = Card::new(Rank::Ace, Suit::Hearts)
+            .size(CardSize::Small)
+            .facing(Facing::Down);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│╱╱╱╱╱╱│", "│╱╱╱╱╱╱│", "│╱╱╱╱╱╱│", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().blue().on_white());
+        assert_eq!(buf, expected);
+        assert!(card.is_face_down());
+    }
+
+    #[test]
+    fn render_tiny() {
+        let render = |card: Card| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+            card.size(CardSize::Tiny).render(buf.area, &mut buf);
+            buf
+        };
+        let ten = render(Card::new(Rank::Ten, Suit::Hearts));
+        let symbols: Vec<_> = ten.content().iter().map(Cell::symbol).collect();
+        assert_eq!(symbols, ["1", "0", "♥\u{FE0E}"]);
+        assert_eq!((ten[(2, 0)].fg, ten[(2, 0)].bg), (Color::Red, Color::White));
+        let ace = render(Card::new(Rank::Ace, Suit::Spades));
+        let symbols: Vec<_> = ace.content().iter().map(Cell::symbol).collect();
+        assert_eq!(symbols, ["A", "♠\u{FE0E}", " "]);
+        let back = render(Card::new(Rank::Ace, Suit::Spades).facing(Facing::Down));
+        let mut expected = Buffer::with_lines(["╱╱╱"]);
+        expected.set_style(expected.area, Style::new().blue().on_white());
+        assert_eq!(back, expected);
+    }
+
+    #[test]
+    fn render_ascii() {
+        let card = Card::new(Rank::Ten, Suit::Diamonds).ascii(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        card.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "+------------+",
+            "|10D     D   |",
+            "|     D      |",
+            "|  D     D   |",
+            "|            |",
+            "|  D     D   |",
+            "|     D      |",
+            "|  D     D 10|",
+            "+------------+",
+        ]);
+        expected.set_style(expected.area, Style::new().blue().on_white());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_ascii_writes_only_ascii() {
+        let cards = all_cards().flat_map(|card| {
+            [
+                card,
+                card.facing(Facing::Down),
+                card.outline_only(true),
+                card.corner_layout(CornerLayout::Stacked),
+                card.theme(CardTheme::new().ace_pip(AcePip::Large)),
+                Card::joker(JokerColor::Red, card.size),
+            ]
+        });
+        for card in cards {
+            let size = card.size.dimensions();
+            let mut buf = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+            card.ascii(true).render(buf.area, &mut buf);
+            for cell in &buf.content {
+                assert!(cell.symbol().is_ascii(), "{card:?} drew {}", cell.symbol());
+            }
+        }
+    }
+
+    #[test]
+    fn render_joker() {
+        let joker = Card::joker(JokerColor::Black, CardSize::Small);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        joker.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│JOKER │", "│  🃏  │", "│ JOKER│", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().black().on_white());
+        // the cell hidden by the wide jester is skipped when rendering
+        expected[(4, 2)].set_style(Style::reset());
+        assert_eq!(buf, expected);
+        assert_eq!(joker.describe(), "Black Joker");
+        assert_eq!(joker.as_unicode_char(), '🃏');
+        assert_eq!(joker.as_colored_symbol(), "🃏");
+        let red = Card::joker(JokerColor::Red, CardSize::Small);
+        assert!(!joker.same_card(&red));
+        assert!(!red.same_card(&Card::new(Rank::King, Suit::Hearts)));
+        assert_eq!(red.color(), Color::Red);
+    }
+
+    #[test]
+    fn render_joker_ghost() {
+        let joker = Card::joker(JokerColor::Red, CardSize::Small).ghost(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        joker.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭╌╌╌╌╌╌╮", "╎JOKER ╎", "╎      ╎", "╎ JOKER╎", "╰╌╌╌╌╌╌╯"]);
+        expected.set_style(expected.area, Style::new().red().on_white().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_custom_back() {
+        let back = CardBack::new("┼─\n│ ").unwrap().style(Style::new().green());
+        let card = Card::new(Rank::Ace, Suit::Hearts)
+            .size(CardSize::Small)
+            .facing(Facing::Down)
+            .back(back);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│┼─┼─┼─│", "││ │ │ │", "│┼─┼─┼─│", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().green().on_white());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn face_down_backs_are_suit_agnostic() {
+        let back = |card: Card| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+            card.facing(Facing::Down).render(buf.area, &mut buf);
+            buf
+        };
+        let spade = back(Card::new(Rank::Ace, Suit::Spades));
+        assert_eq!(back(Card::new(Rank::King, Suit::Hearts)), spade);
+        assert_ne!(
+            back(Card::new(Rank::King, Suit::Hearts).back_tinted(true)),
+            spade
+        );
+    }
+
+    #[test]
+    fn back_style_is_independent_of_suit() {
+        let theme = CardTheme::new().back_style(Style::new().magenta().on_black());
+        let back = |card: Card| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+            card.size(CardSize::Small)
+                .theme(theme)
+                .facing(Facing::Down)
+                .render(buf.area, &mut buf);
+            buf
+        };
+        let hearts = back(Card::new(Rank::Ace, Suit::Hearts));
+        assert_eq!(back(Card::new(Rank::Two, Suit::Clubs)), hearts);
+        assert_eq!(hearts[(1, 1)].fg, Color::Magenta);
+        assert_eq!(hearts[(1, 1)].bg, Color::Black);
+    }
+
+    #[test]
+    fn render_tinted_back() {
+        let card = Card::new(Rank::Two, Suit::Clubs)
+            .size(CardSize::Compact)
+            .facing(Facing::Down)
+            .back_tinted(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        card.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["╱╱╱╱╱╱╱╱", "────────"]);
+        expected.set_style(expected.area, Style::new().green().on_white());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_face_down_glyph() {
+        let card = Card::new(Rank::Queen, Suit::Hearts)
+            .size(CardSize::Glyph)
+            .facing(Facing::Down);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        card.render(buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "🂠");
+        assert_eq!(card.as_unicode_back_char(), '🂠');
+    }
+
+    #[test]
+    fn describe_face_down() {
+        let card = Card::new(Rank::Queen, Suit::Hearts).facing(Facing::Down);
+        assert_eq!(card.describe(), "face-down card");
+        assert_eq!(card.describe_with_color(), "face-down card");
+    }
+
+    #[test]
+    fn turn_upside_down_keeps_tokens_readable() {
+        let template = Rank::Ten.template(CardSize::Small);
+        assert_eq!(
+            turn_upside_down(template),
+            "╭──────╮\n│10    │\n│  xx  │\n│    10│\n╰──────╯"
+        );
+        let seven = Rank::Seven.template(CardSize::Normal);
+        assert_eq!(turn_upside_down(&turn_upside_down(seven)), seven);
+    }
+
+    #[test]
+    fn render_reversed() {
+        let card = Card::new(Rank::Seven, Suit::Hearts).reversed(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        card.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "╭────────────╮",
+            "│ 7♥️    ♥️  │",
+            "│            │",
+            "│            │",
+            "│  ♥️    ♥️  │",
+            "│     ♥️     │",
+            "│            │",
+            "│  ♥️    ♥️7 │",
+            "╰────────────╯",
+        ]);
+        let symbols = |buf: &Buffer| buf.content().iter().map(Cell::symbol).join("");
+        assert_eq!(symbols(&buf), symbols(&expected));
+    }
+
+    #[test]
+    fn into_text() {
+        let card = Card::new(Rank::Ten, Suit::Hearts).size(CardSize::Small);
+        let text = Text::from(card);
+        let style = Style::new().red().on_white();
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(Span::styled("╭──────╮", style)),
+                Line::from(Span::styled("│10    │", style)),
+                Line::from(Span::styled("│  ♥️  │", style)),
+                Line::from(Span::styled("│    10│", style)),
+                Line::from(Span::styled("╰──────╯", style)),
+            ])
+        );
+    }
+
+    #[test]
+    fn to_cells_covers_every_cell() {
+        let card = Card::new(Rank::Ten, Suit::Hearts)
+            .size(CardSize::Small)
+            .highlighted(true);
+        let cells = card.to_cells();
+        let rows: Vec<String> = cells
+            .iter()
+            .map(|row| row.iter().map(|(symbol, _)| symbol).collect())
+            .collect();
+        assert_eq!(
+            rows,
+            ["╭──────╮", "│10    │", "│  ♥   │", "│    10│", "╰──────╯"]
+        );
+        let face = Style::new().red().on_white();
+        let border = face.green();
+        assert_eq!(cells[0][0], ('╭', border));
+        assert_eq!(cells[2][3], ('♥', face));
+        // the cell covered by the wide heart keeps its style
+        assert_eq!(cells[2][4], (' ', face));
+    }
+
+    #[test]
+    fn to_lines_matches_render() {
+        for card in all_cards() {
+            let size = card.size.dimensions();
+            let lines = card.to_lines();
+            assert_eq!(lines.len(), usize::from(size.height), "{card:?}");
+            for line in &lines {
+                assert_eq!(line.width(), usize::from(size.width), "{card:?}");
+            }
+            assert_eq!(card.to_text(), Text::from(lines), "{card:?}");
+            let mut rendered = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+            card.render(rendered.area, &mut rendered);
+            let mut from_text = Buffer::empty(rendered.area);
+            card.to_text().render(from_text.area, &mut from_text);
+            for (rendered, from_text) in zip(&rendered.content, &from_text.content) {
+                assert_eq!(rendered.symbol(), from_text.symbol(), "{card:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let card = Card::new(Rank::Ten, Suit::Hearts);
+        assert_eq!(card.to_string(), "10♥");
+        assert_eq!(format!("{card:#}"), "10H");
+        assert_eq!(card.ascii(true).to_string(), "10H");
+        let joker = Card::joker(JokerColor::Red, CardSize::Normal);
+        assert_eq!(joker.to_string(), "Red Joker");
+        let cards = Rank::iter()
+            .cartesian_product(Suit::iter())
+            .map(|(rank, suit)| Card::new(rank, suit))
+            .chain(JokerColor::iter().map(|color| Card::joker(color, CardSize::Normal)));
+        for card in cards {
+            assert_eq!(card.to_string().parse(), Ok(card));
+            assert_eq!(format!("{card:#}").parse(), Ok(card));
+        }
+    }
+
+    #[test]
+    fn into_text_keeps_border_background() {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(CardSize::Compact)
+            .border_bg(Color::Gray);
+        let text = Text::from(&card);
+        assert_eq!(text.lines.len(), 2);
+        assert_eq!(text.lines[0].spans[0].style.bg, Some(Color::White));
+        let card = card.size(CardSize::Small);
+        let text = Text::from(&card);
+        assert_eq!(text.lines[0].spans[0].style.bg, Some(Color::Gray));
+        assert_eq!(text.lines[1].spans[1].content, "A     ");
+        assert_eq!(text.lines[1].spans[1].style.bg, Some(Color::White));
+    }
+
+    #[test]
+    fn render_without_court_art() {
+        let card = Card::new(Rank::Queen, Suit::Hearts).court_art(false);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        card.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "╭────────────╮",
+            "│ Q♥️        │",
+            "│            │",
+            "│            │",
+            "│      Q     │",
+            "│            │",
+            "│            │",
+            "│        ♥️Q │",
+            "╰────────────╯",
+        ]);
+        let symbols = |buf: &Buffer| buf.content().iter().map(Cell::symbol).join("");
+        assert_eq!(symbols(&buf), symbols(&expected));
+    }
+
+    #[test]
+    fn court_art_ignores_number_cards() {
+        let card = Card::new(Rank::Ten, Suit::Clubs).size(CardSize::Large);
+        let mut with_art = Buffer::empty(Rect::new(0, 0, 20, 13));
+        let mut without = with_art.clone();
+        card.render(with_art.area, &mut with_art);
+        card.court_art(false).render(without.area, &mut without);
+        assert_eq!(without, with_art);
+    }
+
+    #[test]
+    fn render_glyph() {
+        let card = Card::new(Rank::Queen, Suit::Hearts).size(CardSize::Glyph);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        card.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["🂽 "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red().on_white());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn glyph_skips_knight() {
+        assert_eq!(Card::new(Rank::Jack, Suit::Spades).as_unicode_char(), '🂫');
+        assert_eq!(Card::new(Rank::Queen, Suit::Spades).as_unicode_char(), '🂭');
+        assert_eq!(Card::new(Rank::King, Suit::Clubs).as_unicode_char(), '🃞');
+        assert_eq!(Card::new(Rank::Ace, Suit::Diamonds).as_unicode_char(), '🃁');
+        assert_eq!(Card::new(Rank::Ten, Suit::Hearts).as_unicode_char(), '🂺');
+        assert_eq!(Card::new(Rank::Two, Suit::Clubs).as_unicode_char(), '🃒');
+    }
+
+    #[test]
+    fn with_style_fn_sees_the_card() {
+        let style = |card: &Card| {
+            if card.suit.is_red() {
+                card.theme.background(Color::Yellow)
+            } else {
+                card.theme
+            }
+        };
+        let hearts = Card::new(Rank::Ace, Suit::Hearts).with_style_fn(style);
+        let spades = Card::new(Rank::Ace, Suit::Spades).with_style_fn(style);
+        assert_eq!(hearts.theme.background, Color::Yellow);
+        assert_eq!(spades.theme, CardTheme::default());
+    }
+
+    #[test]
+    fn highlight_styles_the_border() {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(CardSize::Small)
+            .highlighted(true)
+            .highlight_style(Style::new().yellow().bold());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│A     │", "│  ♠️  │", "│     A│", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().black().on_white());
+        expected[(4, 2)].set_style(Style::reset());
+        for position in border_positions(expected.area) {
+            expected[position].set_style(Style::new().yellow().bold());
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn highlight_style_is_ignored_unless_highlighted() {
+        let card = Card::new(Rank::Ace, Suit::Spades);
+        let mut plain = Buffer::empty(Rect::new(0, 0, 14, 9));
+        let mut styled = plain.clone();
+        card.render(plain.area, &mut plain);
+        card.highlight_style(Style::new().red())
+            .render(styled.area, &mut styled);
+        assert_eq!(styled, plain);
+    }
+
+    #[test]
+    fn highlight_compact_underline() {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(CardSize::Compact)
+            .highlighted(true)
+            .highlight_style(Style::new().yellow());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        card.render(buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Black);
+        assert_eq!(buf[(0, 1)].fg, Color::Yellow);
+        assert_eq!(buf[(7, 1)].fg, Color::Yellow);
+    }
+
+    #[test]
+    fn default_faces_cover_boxed_and_compact_cards() {
+        for card in
+            all_cards().filter(|card| !matches!(card.size, CardSize::Glyph | CardSize::Tiny))
+        {
+            let key = card.face_key();
+            assert_eq!(DEFAULT_FACES.get(&key), Some(&key.build()), "{card:?}");
+        }
+        let reversed = Card::new(Rank::Two, Suit::Clubs).reversed(true);
+        assert!(!DEFAULT_FACES.contains_key(&reversed.face_key()));
+    }
+
+    #[test]
+    fn emphasize_rank_modifies_only_the_indices() {
+        let card = Card::new(Rank::Ten, Suit::Hearts)
+            .size(CardSize::Small)
+            .reversed(true)
+            .emphasize_rank(Modifier::UNDERLINED);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let emphasized: Vec<_> = buf
+            .area
+            .positions()
+            .filter(|position| buf[*position].modifier.contains(Modifier::UNDERLINED))
+            .map(|position| buf[position].symbol().to_string())
+            .collect();
+        assert_eq!(emphasized, ["1", "0", "1", "0"]);
+    }
+
+    #[test]
+    fn render_centered_centers_and_clamps() {
+        let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 13, 10));
+        card.render_centered(Rect::new(1, 1, 12, 9), &mut buf);
+        assert_eq!(buf[(3, 3)].symbol(), "╭");
+        assert_eq!(buf[(10, 7)].symbol(), "╯");
+        let mut small = Buffer::empty(Rect::new(0, 0, 4, 3));
+        card.render_centered(small.area, &mut small);
+        assert_eq!(small[(0, 0)].symbol(), "╭");
+        assert_eq!(small[(1, 1)].symbol(), "A");
+    }
+
+    #[test]
+    fn render_aligned() {
+        let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+        let area = Rect::new(0, 0, 20, 11);
+        let corners = [
+            (Alignment::Left, VerticalAlignment::Top, (0, 0), (7, 4)),
+            (
+                Alignment::Center,
+                VerticalAlignment::Center,
+                (6, 3),
+                (13, 7),
+            ),
+            (
+                Alignment::Right,
+                VerticalAlignment::Bottom,
+                (12, 6),
+                (19, 10),
+            ),
+        ];
+        for (alignment, vertical_alignment, top_left, bottom_right) in corners {
+            let mut buf = Buffer::empty(area);
+            card.alignment(alignment)
+                .vertical_alignment(vertical_alignment)
+                .highlighted(true)
+                .render(area, &mut buf);
+            assert_eq!(buf[top_left].symbol(), "╭", "{alignment}");
+            assert_eq!(buf[bottom_right].symbol(), "╯", "{alignment}");
+            let card_area = Rect::new(top_left.0, top_left.1, 8, 5);
+            for position in area.positions().filter(|p| !card_area.contains(*p)) {
+                assert_eq!(buf[position], Cell::EMPTY, "{alignment} {position:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn alignment_ignored_when_area_is_too_small() {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(CardSize::Small)
+            .alignment(Alignment::Right)
+            .vertical_alignment(VerticalAlignment::Bottom);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        card.render(buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "╭");
+        assert_eq!(buf[(1, 1)].symbol(), "A");
+    }
+
+    #[test]
+    fn render_clipped_reveals_columns_from_each_side() {
+        let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+        let mut full = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(full.area, &mut full);
+        for side in [RevealSide::Left, RevealSide::Right] {
+            for reveal in 0..=8 {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+                card.render_clipped(buf.area, &mut buf, reveal, side);
+                for position in buf.area.positions() {
+                    let cell = &buf[position];
+                    if position.x >= reveal {
+                        assert_eq!(cell, &Cell::EMPTY, "{side:?} {reveal} {position:?}");
+                        continue;
+                    }
+                    let source = match side {
+                        RevealSide::Left => position.x,
+                        RevealSide::Right => position.x + 8 - reveal,
+                    };
+                    let wide_cut = (side == RevealSide::Left && source == 3 && reveal == 4)
+                        || (side == RevealSide::Right && source == 4 && position.x == 0);
+                    if position.y == 2 && wide_cut {
+                        assert_eq!(cell.symbol(), " ", "{side:?} {reveal}");
+                        assert_eq!(cell.bg, Color::White);
+                    } else {
+                        assert_eq!(cell, &full[(source, position.y)], "{side:?} {reveal}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tags_do_not_affect_rendering_or_identity() {
+        let card = Card::new(Rank::Two, Suit::Hearts);
+        let tagged = card.tag(0b101);
+        assert!(tagged.has_tag(0b001));
+        assert!(tagged.has_tag(0b101));
+        assert!(!tagged.has_tag(0b011));
+        assert!(tagged.same_card(&card));
+        assert_ne!(tagged, card);
+        assert_eq!(Text::from(tagged), Text::from(card));
+    }
+
+    #[test]
+    fn render_key_tracks_presentation() {
+        let card = Card::new(Rank::Ace, Suit::Spades);
+        let key = card.render_key();
+        assert_eq!(Card::new(Rank::Ace, Suit::Spades).render_key(), key);
+        for changed in [
+            Card::new(Rank::Two, Suit::Spades),
+            card.size(CardSize::Small),
+            card.facing(Facing::Down),
+            card.highlighted(true),
+            card.theme(card.theme.background(Color::Yellow)),
+        ] {
+            assert_ne!(changed.render_key(), key, "{changed:?}");
+        }
+    }
+
+    #[test]
+    fn set_size_renders_at_new_size() {
+        let mut card = Card::new(Rank::Four, Suit::Clubs);
+        card.set_size(CardSize::Small);
+        let mut resized = Buffer::empty(Rect::new(0, 0, 14, 9));
+        let mut built = resized.clone();
+        card.render(resized.area, &mut resized);
+        Card::new(Rank::Four, Suit::Clubs)
+            .size(CardSize::Small)
+            .render(built.area, &mut built);
+        assert_eq!(resized, built);
+        assert_eq!(resized[(7, 0)].symbol(), "╮");
+    }
+
+    #[test]
+    fn marker_replaces_top_right_corner() {
+        let card = Card::new(Rank::Ten, Suit::Hearts)
+            .size(CardSize::Small)
+            .highlighted(true)
+            .marker(Some(('★', Style::new().yellow())));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let symbols = |buf: &Buffer| buf.content().iter().map(Cell::symbol).join("");
+        let expected =
+            Buffer::with_lines(["╭──────★", "│10    │", "│  ♥️  │", "│    10│", "╰──────╯"]);
+        assert_eq!(symbols(&buf), symbols(&expected));
+        assert_eq!(buf[(7, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(7, 0)].bg, Color::White);
+    }
+
+    #[test]
+    fn marker_on_compact_and_glyph_cards() {
+        let marker = Some(('!', Style::new()));
+        let card = Card::new(Rank::Ten, Suit::Hearts)
+            .size(CardSize::Compact)
+            .marker(marker);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        card.render(buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "1");
+        assert_eq!(buf[(7, 0)].symbol(), "!");
+        let glyph = Card::new(Rank::Ten, Suit::Hearts).size(CardSize::Glyph);
+        let mut plain = Buffer::empty(Rect::new(0, 0, 2, 1));
+        let mut marked = plain.clone();
+        glyph.render(plain.area, &mut plain);
+        glyph.marker(marker).render(marked.area, &mut marked);
+        assert_eq!(marked, plain);
+    }
+
+    #[test]
+    fn bevel_colors_border_by_edge() {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(CardSize::Small)
+            .bevel(Color::White, Color::DarkGray);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let colors: Vec<String> = buf
+            .area
+            .rows()
+            .map(|row| {
+                row.positions()
+                    .map(|position| match buf[position].fg {
+                        Color::White => 'L',
+                        Color::DarkGray => 'D',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            colors,
+            ["LLLLLLLL", "L......D", "L......D", "L......D", "LDDDDDDD"]
+        );
+    }
+
+    #[test]
+    fn bevel_ignores_unboxed_cards() {
+        for size in [CardSize::Glyph, CardSize::Compact] {
+            let card = Card::new(Rank::Ace, Suit::Spades).size(size);
+            let dimensions = size.dimensions();
+            let mut flat = Buffer::empty(Rect::new(0, 0, dimensions.width, dimensions.height));
+            let mut beveled = flat.clone();
+            card.render(flat.area, &mut flat);
+            card.bevel(Color::White, Color::DarkGray)
+                .render(beveled.area, &mut beveled);
+            assert_eq!(beveled, flat, "{size:?}");
+        }
+    }
+
+    #[test]
+    fn interior_fill_fills_blank_cells() {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(CardSize::Small)
+            .interior_fill('·', Style::new().gray());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        card.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│A·····│", "│··♠️··│", "│·····A│", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().black().on_white());
+        expected[(4, 2)].set_style(Style::reset());
+        for position in Rect::new(1, 1, 6, 3).positions() {
+            if expected[position].symbol() == "·" {
+                expected[position].set_style(Style::new().gray());
+            }
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn interior_fill_skips_backs_and_compact_cards() {
+        for card in [
+            Card::new(Rank::Ace, Suit::Spades).facing(Facing::Down),
+            Card::new(Rank::Ace, Suit::Spades).size(CardSize::Compact),
+        ] {
+            let size = card.size.dimensions();
+            let mut plain = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+            let mut filled = plain.clone();
+            card.render(plain.area, &mut plain);
+            card.interior_fill('·', Style::new())
+                .render(filled.area, &mut filled);
+            assert_eq!(filled, plain, "{card:?}");
+        }
+    }
+
+    #[test]
+    fn validate_default_cards() {
+        for card in all_cards() {
+            assert_eq!(card.validate(), Ok(()), "{card:?}");
+        }
+    }
+
+    #[test]
+    fn validate_reports_invisible_suit() {
+        let theme = CardTheme::new().background(Color::Red);
+        assert_eq!(
+            Card::new(Rank::Two, Suit::Hearts).theme(theme).validate(),
+            Err(CardError::SuitMatchesBackground(Suit::Hearts))
+        );
+        // only the card's own suit matters
+        assert_eq!(
+            Card::new(Rank::Two, Suit::Spades).theme(theme).validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_reports_invisible_back() {
+        let theme = CardTheme::new().back_style(Style::new().fg(Color::White));
+        let card = Card::new(Rank::Two, Suit::Hearts).theme(theme);
+        assert_eq!(card.validate(), Err(CardError::BackMatchesBackground));
+        // a tinted back is drawn in the suit color instead
+        assert_eq!(card.back_tinted(true).validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_wide_back_symbol() {
+        let card = Card::new(Rank::Two, Suit::Hearts).back(CardBack::solid('中'));
+        assert_eq!(
+            card.validate(),
+            Err(CardError::BackSymbolNotSingleWidth('中'))
+        );
+        assert_eq!(card.back(CardBack::solid('░')).validate(), Ok(()));
+    }
+
+    #[test]
+    fn card_error_display() {
+        assert_eq!(
+            CardError::SuitMatchesBackground(Suit::Clubs).to_string(),
+            "Clubs are drawn in the background color"
+        );
+        assert_eq!(
+            CardError::SymbolTooWide {
+                presentation: SymbolPresentation::Emoji,
+                width: 3
+            }
+            .to_string(),
+            "the Emoji suit symbol is 3 columns wide, but at most 2 fit"
+        );
+    }
+
+    #[test]
+    fn same_card_compares_rank_and_suit() {
+        let card = Card::new(Rank::Seven, Suit::Diamonds);
+        assert!(card.same_card(&card));
+        assert!(!card.same_card(&Card::new(Rank::Eight, Suit::Diamonds)));
+        assert!(!card.same_card(&Card::new(Rank::Seven, Suit::Clubs)));
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            Card::new(Rank::Ace, Suit::Spades).describe(),
+            "Ace of Spades"
+        );
+        assert_eq!(Card::new(Rank::Ten, Suit::Clubs).describe(), "Ten of Clubs");
+    }
+
+    #[test]
+    fn describe_with_color() {
+        assert_eq!(
+            Card::new(Rank::King, Suit::Hearts).describe_with_color(),
+            "King of Hearts (red)"
+        );
+        assert_eq!(
+            Card::new(Rank::Three, Suit::Clubs).describe_with_color(),
+            "Three of Clubs (green)"
+        );
+        let theme = CardTheme::new().suits(SuitTheme::colorblind_friendly());
+        assert_eq!(
+            Card::new(Rank::Ace, Suit::Spades)
+                .theme(theme)
+                .describe_with_color(),
+            "Ace of Spades (black)"
+        );
+        assert_eq!(
+            Card::new(Rank::Ace, Suit::Hearts)
+                .theme(theme)
+                .describe_with_color(),
+            "Ace of Hearts (orange)"
+        );
+    }
+}
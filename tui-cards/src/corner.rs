@@ -0,0 +1,235 @@
+use ratatui_core::layout::Position;
+
+use crate::{CardSize, Rank};
+
+/// How the rank and suit are arranged in the corners of a [`CardSize::Normal`] card.
+///
+/// Other sizes ignore this setting: small cards only show the rank in their corners, and large
+/// cards always show the rank and suit side by side.
+///
+/// [`CardSize::Normal`]: crate::CardSize::Normal
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CornerLayout {
+    /// The rank and a suit symbol are drawn next to each other on a single line, e.g. `Q♠`.
+    #[default]
+    SideBySide,
+    /// The rank is drawn on the first line and the suit symbol on the line below it, like the
+    /// indices of a real card.
+    Stacked,
+}
+
+/// Rearranges the corners of a normal size template into the stacked layout.
+///
+/// The suit symbol (`xx`) is placed under the top left index and above the bottom right index.
+/// Court cards pair their index with a suit symbol beside it, which is removed. Number cards keep
+/// the pips beside their index, as those are part of the pip layout rather than the index.
+pub(crate) fn stack_corners(template: &str, rank: Rank) -> String {
+    let mut lines: Vec<Vec<char>> = template
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    if lines.len() < 9 {
+        return template.to_string();
+    }
+    if rank.is_face() {
+        replace(&mut lines[1], 3, "  ");
+        replace(&mut lines[7], 9, "  ");
+    }
+    replace(&mut lines[2], 1, "xx");
+    replace(&mut lines[6], 11, "xx");
+    lines
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Marks the suit symbols of the corner indices in a template by replacing their `xx` with `cc`,
+/// so they can be drawn with a different symbol than the pips.
+///
+/// In the stacked layout these are the symbols under and above the indices. Otherwise they are the
+/// symbols directly beside the indices, except on normal size number cards where those symbols are
+/// part of the pip layout.
+pub(crate) fn mark_corner_symbols(
+    template: &str,
+    rank: Rank,
+    size: CardSize,
+    stacked: bool,
+) -> String {
+    if stacked {
+        let mut lines: Vec<Vec<char>> = template
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect();
+        if lines.len() < 9 {
+            return template.to_string();
+        }
+        replace(&mut lines[2], 1, "cc");
+        replace(&mut lines[6], 11, "cc");
+        return lines
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    if size == CardSize::Normal && rank.is_number() {
+        return template.to_string();
+    }
+    let index = rank.short_name();
+    let mut template = template.replacen(&format!("{index}xx"), &format!("{index}cc"), 1);
+    let bottom = format!("xx{index}");
+    if let Some(start) = template.rfind(&bottom) {
+        template.replace_range(start..start + 2, "cc");
+    }
+    template
+}
+
+/// Returns the cells of the rank indices in a template, as offsets from its top left.
+///
+/// The top index is the first one on the line below the top border, and the bottom index the last
+/// one on the line above the bottom border, so letters in the court art are never mistaken for
+/// them. Compact templates only have the index on their first line.
+pub(crate) fn index_cells(template: &str, rank: Rank) -> Vec<Position> {
+    let index: Vec<char> = rank.short_name().chars().collect();
+    let lines: Vec<Vec<char>> = template
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    let rows = match lines.len() {
+        0 => vec![],
+        1 | 2 => vec![(0, false)],
+        len => vec![(1, false), (len - 2, true)],
+    };
+    rows.into_iter()
+        .filter_map(|(row, last)| {
+            let mut windows = lines[row].windows(index.len());
+            let column = if last {
+                windows.rposition(|window| window == index)
+            } else {
+                windows.position(|window| window == index)
+            }?;
+            Some((row, column))
+        })
+        .flat_map(|(row, column)| {
+            (column..column + index.len())
+                .map(move |column| Position::new(column as u16, row as u16))
+        })
+        .collect()
+}
+
+fn replace(line: &mut [char], start: usize, with: &str) {
+    for (cell, ch) in line.iter_mut().skip(start).zip(with.chars()) {
+        *cell = ch;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::CardSize;
+
+    #[test]
+    fn stacked_suit_only_replaces_blank_cells() {
+        for rank in Rank::iter() {
+            let template = rank.template(CardSize::Normal);
+            let lines: Vec<Vec<char>> = template.lines().map(|l| l.chars().collect()).collect();
+            assert_eq!(lines[2][1..3], [' ', ' '], "{rank}");
+            assert_eq!(lines[6][11..13], [' ', ' '], "{rank}");
+        }
+    }
+
+    #[test]
+    fn stacks_ten() {
+        let template = stack_corners(Rank::Ten.template(CardSize::Normal), Rank::Ten);
+        assert_eq!(
+            template.lines().collect::<Vec<_>>(),
+            [
+                "╭────────────╮",
+                "│10xx    xx  │",
+                "│xx   xx     │",
+                "│  xx    xx  │",
+                "│            │",
+                "│  xx    xx  │",
+                "│     xx   xx│",
+                "│  xx    xx10│",
+                "╰────────────╯",
+            ]
+        );
+    }
+
+    #[test]
+    fn marks_corner_symbols_beside_index() {
+        let template = mark_corner_symbols(
+            Rank::Ten.template(CardSize::Large),
+            Rank::Ten,
+            CardSize::Large,
+            false,
+        );
+        let lines: Vec<_> = template.lines().collect();
+        assert_eq!(lines[1], "│10cc              │");
+        assert_eq!(lines[2], "│    xx      xx    │");
+        assert_eq!(lines[11], "│              cc10│");
+    }
+
+    #[test]
+    fn normal_number_cards_have_no_corner_symbols() {
+        let template = Rank::Four.template(CardSize::Normal);
+        assert_eq!(
+            mark_corner_symbols(template, Rank::Four, CardSize::Normal, false),
+            template
+        );
+    }
+
+    #[test]
+    fn marks_stacked_corner_symbols() {
+        let stacked = stack_corners(Rank::Four.template(CardSize::Normal), Rank::Four);
+        let template = mark_corner_symbols(&stacked, Rank::Four, CardSize::Normal, true);
+        let lines: Vec<_> = template.lines().collect();
+        assert_eq!(lines[1], "│ 4xx    xx  │");
+        assert_eq!(lines[2], "│cc          │");
+        assert_eq!(lines[6], "│          cc│");
+    }
+
+    #[test]
+    fn index_cells_skip_court_art() {
+        let template = Rank::Jack.template(CardSize::Normal);
+        assert_eq!(
+            index_cells(template, Rank::Jack),
+            [Position::new(2, 1), Position::new(11, 7)]
+        );
+        let template = Rank::Ten.template(CardSize::Small);
+        assert_eq!(
+            index_cells(template, Rank::Ten),
+            [
+                Position::new(1, 1),
+                Position::new(2, 1),
+                Position::new(5, 3),
+                Position::new(6, 3)
+            ]
+        );
+        let template = Rank::Queen.template(CardSize::Compact);
+        assert_eq!(index_cells(template, Rank::Queen), [Position::new(0, 0)]);
+    }
+
+    #[test]
+    fn stacks_court_card() {
+        let template = stack_corners(Rank::Jack.template(CardSize::Normal), Rank::Jack);
+        assert_eq!(
+            template.lines().collect::<Vec<_>>(),
+            [
+                "╭────────────╮",
+                "│ J          │",
+                "│xx     JJ   │",
+                "│       JJ   │",
+                "│       JJ   │",
+                "│  JJ   JJ   │",
+                "│   JJJJJ  xx│",
+                "│          J │",
+                "╰────────────╯",
+            ]
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Helpers for eyeballing the rendering of every card while developing.
+
+use itertools::Itertools;
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::text::{Line, Text};
+use ratatui_core::widgets::Widget;
+use strum::EnumCount;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{Card, CardSize, Deck, Rank};
+
+/// Renders every card of a [`Deck::standard`] deck at the given size, wrapping into rows.
+///
+/// Cards are laid out left to right with a one cell gap between them, starting a new row when the
+/// next card would not fit. Only cards that fit entirely within the area are rendered, so clipped
+/// cards never hide rendering issues. Returns the number of cards rendered.
+///
+/// This is intended for quickly spotting rendering problems during development, not for use in
+/// applications.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use tui_cards::{debug_render_deck, CardSize};
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+/// let rendered = debug_render_deck(buf.area, &mut buf, CardSize::Small);
+/// assert_eq!(rendered, 36);
+/// ```
+pub fn debug_render_deck(area: Rect, buf: &mut Buffer, size: CardSize) -> usize {
+    let area = area.intersection(buf.area);
+    let deck = Deck::standard();
+    let mut rendered = 0;
+    for (card, card_area) in deck.layout_in(area, size, 1) {
+        card.render(card_area, buf);
+        rendered += 1;
+    }
+    rendered
+}
+
+/// Renders the same card at each of the given sizes side by side, each under a label naming its
+/// size, to compare how the sizes look.
+///
+/// Each size gets a column as wide as the wider of its card and its label, with a one cell gap
+/// between columns. Only sizes whose card fits entirely within the area below its label are
+/// rendered, stopping at the first that does not. Returns the number of sizes rendered.
+///
+/// This is intended for checking that the sizes stay consistent with each other during
+/// development, not for use in applications.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use tui_cards::{debug_render_sizes, Card, CardSize, Rank, Suit};
+///
+/// let card = Card::new(Rank::Queen, Suit::Hearts);
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+/// let sizes = [CardSize::Small, CardSize::Normal];
+/// assert_eq!(debug_render_sizes(buf.area, &mut buf, &card, &sizes), 2);
+/// ```
+pub fn debug_render_sizes(area: Rect, buf: &mut Buffer, card: &Card, sizes: &[CardSize]) -> usize {
+    let area = area.intersection(buf.area);
+    let mut x = area.x;
+    let mut rendered = 0;
+    for &size in sizes {
+        let label = format!("{size:?}");
+        let dimensions = size.dimensions();
+        let width = dimensions.width.max(label.width() as u16);
+        let column = Rect::new(x, area.y, width, dimensions.height.saturating_add(1));
+        if x.saturating_add(width) > area.right() || column.bottom() > area.bottom() {
+            break;
+        }
+        Line::raw(label).render(Rect::new(x, area.y, width, 1), buf);
+        let card_area = Rect::new(x, area.y + 1, dimensions.width, dimensions.height);
+        card.size(size).render(card_area, buf);
+        rendered += 1;
+        x = x.saturating_add(width).saturating_add(1);
+    }
+    rendered
+}
+
+impl Deck {
+    /// Returns the cards of the deck at the given size as plain text, laid out in rows of
+    /// [`Rank::COUNT`] cards, for a printable reference or a README.
+    ///
+    /// A standard deck therefore has one row per suit. Cards are separated by a space and rows by
+    /// a blank line, and trailing spaces are trimmed. The colors of the cards are lost, so this is
+    /// best suited to checking the shapes of the cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardSize, Deck};
+    ///
+    /// let reference = Deck::standard().to_reference_string(CardSize::Compact);
+    /// assert!(reference.starts_with("A♠️      2♠️"));
+    /// assert_eq!(reference.lines().count(), 4 * 3 - 1);
+    /// ```
+    pub fn to_reference_string(&self, size: CardSize) -> String {
+        self.cards()
+            .chunks(Rank::COUNT)
+            .map(|row| {
+                let cards: Vec<Text> = row.iter().map(|card| Text::from(card.size(size))).collect();
+                (0..usize::from(size.dimensions().height))
+                    .map(|line| {
+                        let line = cards
+                            .iter()
+                            .map(|card| card.lines[line].to_string())
+                            .join(" ");
+                        line.trim_end().to_string()
+                    })
+                    .join("\n")
+            })
+            .join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Suit;
+
+    #[test]
+    fn renders_whole_deck_when_it_fits() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 13 * 2, 4 * 2));
+        let rendered = debug_render_deck(buf.area, &mut buf, CardSize::Glyph);
+        assert_eq!(rendered, 52);
+        assert_eq!(buf[(0, 0)].symbol(), "🂡");
+        assert_eq!(buf[(24, 6)].symbol(), "🃞");
+    }
+
+    #[test]
+    fn counts_only_cards_that_fit() {
+        // exactly two normal cards side by side, with room for a third clipped one
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14 * 2 + 1 + 5, 9));
+        assert_eq!(debug_render_deck(buf.area, &mut buf, CardSize::Normal), 2);
+        assert_eq!(buf[(30, 0)].symbol(), " ");
+    }
+
+    #[test]
+    fn empty_area_renders_nothing() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 10));
+        assert_eq!(debug_render_deck(Rect::ZERO, &mut buf, CardSize::Small), 0);
+    }
+
+    #[test]
+    fn renders_sizes_side_by_side_under_labels() {
+        let card = Card::new(Rank::Ace, Suit::Spades);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 6));
+        let sizes = [CardSize::Glyph, CardSize::Small, CardSize::Normal];
+        assert_eq!(debug_render_sizes(buf.area, &mut buf, &card, &sizes), 2);
+        let expected = Buffer::with_lines([
+            "Glyph Small     ",
+            "🂡     ╭──────╮  ",
+            "      │A     │  ",
+            "      │  ♠️  │  ",
+            "      │     A│  ",
+            "      ╰──────╯  ",
+        ]);
+        let symbols = |buf: &Buffer| buf.content().iter().map(|cell| cell.symbol()).join("");
+        assert_eq!(symbols(&buf), symbols(&expected));
+    }
+
+    #[test]
+    fn reference_string_of_small_cards() {
+        let deck = Deck::new(Deck::standard().cards()[..2].iter().copied());
+        assert_eq!(
+            deck.to_reference_string(CardSize::Small),
+            indoc::indoc! {"
+                ╭──────╮ ╭──────╮
+                │A     │ │2     │
+                │  ♠️  │ │  ♠️  │
+                │     A│ │     2│
+                ╰──────╯ ╰──────╯"}
+        );
+    }
+
+    #[test]
+    fn reference_string_wraps_rows_of_ranks() {
+        let reference = Deck::standard().to_reference_string(CardSize::Glyph);
+        assert_eq!(
+            reference.lines().collect::<Vec<_>>(),
+            [
+                "🂡 🂢 🂣 🂤 🂥 🂦 🂧 🂨 🂩 🂪 🂫 🂭 🂮",
+                "",
+                "🂱 🂲 🂳 🂴 🂵 🂶 🂷 🂸 🂹 🂺 🂻 🂽 🂾",
+                "",
+                "🃁 🃂 🃃 🃄 🃅 🃆 🃇 🃈 🃉 🃊 🃋 🃍 🃎",
+                "",
+                "🃑 🃒 🃓 🃔 🃕 🃖 🃗 🃘 🃙 🃚 🃛 🃝 🃞",
+            ]
+        );
+        assert_eq!(Deck::default().to_reference_string(CardSize::Glyph), "");
+    }
+}
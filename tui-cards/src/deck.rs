@@ -0,0 +1,989 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use itertools::Itertools;
+use ratatui_core::layout::{Rect, Size};
+use strum::{EnumCount, IntoEnumIterator};
+
+use crate::{Card, CardSize, CardTheme, JokerColor, Rank, Suit, SuitOrder};
+
+/// The number of cards in a [`Deck::standard`] deck: one of every rank in every suit.
+pub const STANDARD_DECK_SIZE: usize = Rank::COUNT * Suit::COUNT;
+
+/// An ordered pile of playing cards.
+///
+/// The first card is the top of the deck.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::Deck;
+///
+/// let deck = Deck::standard();
+/// assert_eq!(deck.len(), 52);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+/// An error returned when a deck is not a valid standard deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeckError {
+    /// The card of the given rank and suit appears more than once in the deck.
+    Duplicate(Rank, Suit),
+    /// The card of the given rank and suit does not appear in the deck.
+    Missing(Rank, Suit),
+    /// The joker of the given color is in the deck, but is not one of the cards of a standard
+    /// deck.
+    Extra(JokerColor),
+    /// There are no ranks or no suits to build the deck from.
+    NoParts,
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate(rank, suit) => write!(f, "duplicate card: {rank} of {suit}"),
+            Self::Missing(rank, suit) => write!(f, "missing card: {rank} of {suit}"),
+            Self::Extra(color) => write!(f, "not a standard card: {color} Joker"),
+            Self::NoParts => write!(f, "no ranks or no suits to build the deck from"),
+        }
+    }
+}
+
+impl Error for DeckError {}
+
+impl Deck {
+    /// Creates a deck from the given cards, the first card being the top of the deck.
+    pub fn new<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        Self {
+            cards: cards.into_iter().collect(),
+        }
+    }
+
+    /// Creates a standard deck of [`STANDARD_DECK_SIZE`] (52) cards.
+    ///
+    /// The cards are ordered by suit and then by rank, starting with the Ace of Spades.
+    pub fn standard() -> Self {
+        let mut cards = Vec::with_capacity(STANDARD_DECK_SIZE);
+        cards.extend(
+            Suit::iter()
+                .cartesian_product(Rank::iter())
+                .map(|(suit, rank)| Card::new(rank, suit)),
+        );
+        Self { cards }
+    }
+
+    /// Creates a standard deck with the two jokers added at the bottom: the red joker, then the
+    /// black one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Deck, JokerColor};
+    ///
+    /// let deck = Deck::with_jokers();
+    /// assert_eq!(deck.len(), 54);
+    /// assert_eq!(deck.cards()[52].joker, Some(JokerColor::Red));
+    /// assert_eq!(deck.cards()[53].joker, Some(JokerColor::Black));
+    /// ```
+    pub fn with_jokers() -> Self {
+        let mut deck = Self::standard();
+        deck.cards
+            .extend(JokerColor::iter().map(|color| Card::joker(color, CardSize::Normal)));
+        deck
+    }
+
+    /// Creates a deck of one card of each of the given ranks in each of the given suits, at the
+    /// given size.
+    ///
+    /// The cards are ordered by suit and then by rank, in the order they are given, like
+    /// [`Deck::standard`]. This builds decks such as a red only or an aces only deck.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeckError::NoParts`] if either slice is empty, and [`DeckError::Duplicate`] if a
+    /// rank or suit is given more than once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardSize, Deck, Rank, Suit};
+    ///
+    /// let red = Deck::from_parts(&Rank::ALL, &[Suit::Hearts, Suit::Diamonds], CardSize::Small)?;
+    /// assert_eq!(red.len(), 26);
+    /// # Ok::<(), tui_cards::DeckError>(())
+    /// ```
+    pub fn from_parts(ranks: &[Rank], suits: &[Suit], size: CardSize) -> Result<Self, DeckError> {
+        if ranks.is_empty() || suits.is_empty() {
+            return Err(DeckError::NoParts);
+        }
+        let deck = Self::new(
+            suits
+                .iter()
+                .cartesian_product(ranks)
+                .map(|(&suit, &rank)| Card::new(rank, suit).size(size)),
+        );
+        match deck.first_duplicate() {
+            Some(card) => Err(DeckError::Duplicate(card.rank, card.suit)),
+            None => Ok(deck),
+        }
+    }
+
+    /// Returns the cards in the deck, from top to bottom.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns the top `n` cards of the deck without removing them, e.g. to show the next cards
+    /// of the stock.
+    ///
+    /// The first card returned is the top of the deck. If the deck has fewer than `n` cards, all
+    /// of them are returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Deck, Rank, Suit};
+    ///
+    /// let deck = Deck::standard();
+    /// assert_eq!(deck.peek(2)[1], Card::new(Rank::Two, Suit::Spades));
+    /// assert_eq!(deck.peek(60).len(), 52);
+    /// ```
+    pub fn peek(&self, n: usize) -> &[Card] {
+        &self.cards[..n.min(self.cards.len())]
+    }
+
+    /// Returns the top card of the deck without removing it, or `None` if the deck is empty.
+    pub fn peek_top(&self) -> Option<&Card> {
+        self.cards.first()
+    }
+
+    /// Lays the cards out in a grid within the area, returning each card at the given size along
+    /// with the area to render it in.
+    ///
+    /// Cards are placed left to right from the top of the deck, with `gap` columns and rows
+    /// between them, starting a new row when the next card would not fit. The iterator stops at
+    /// the first card that does not fit entirely within the area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardSize, Deck};
+    ///
+    /// # fn draw(frame: &mut ratatui::Frame) {
+    /// let deck = Deck::standard();
+    /// for (card, area) in deck.layout_in(frame.area(), CardSize::Small, 1) {
+    ///     frame.render_widget(&card, area);
+    /// }
+    /// # }
+    /// ```
+    pub fn layout_in(
+        &self,
+        area: Rect,
+        size: CardSize,
+        gap: u16,
+    ) -> impl Iterator<Item = (Card, Rect)> + '_ {
+        grid_layout(&self.cards, area, size, gap)
+    }
+
+    /// Returns the number of cards in the deck.
+    pub const fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if there are no cards in the deck.
+    pub const fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns the number of cards left to deal. This is the same as [`Deck::len`].
+    pub const fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Removes the top card of the deck and returns it, or `None` if the deck is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Deck, Rank, Suit};
+    ///
+    /// let mut deck = Deck::standard();
+    /// assert_eq!(deck.deal(), Some(Card::new(Rank::Ace, Suit::Spades)));
+    /// assert_eq!(deck.remaining(), 51);
+    /// ```
+    pub fn deal(&mut self) -> Option<Card> {
+        if self.cards.is_empty() {
+            return None;
+        }
+        Some(self.cards.remove(0))
+    }
+
+    /// Sets the size of every card in the deck, which is [`CardSize::Normal`] for the cards of
+    /// [`Deck::standard`] and [`Deck::with_jokers`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardSize, Deck};
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.set_size(CardSize::Small);
+    /// assert_eq!(deck.deal().map(|card| card.size), Some(CardSize::Small));
+    /// ```
+    pub fn set_size(&mut self, size: CardSize) {
+        for card in &mut self.cards {
+            card.set_size(size);
+        }
+    }
+
+    /// Sets the theme of every card to the one returned by the closure, which is given each card.
+    ///
+    /// See [`Card::with_style_fn`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Deck, SuitTheme};
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.style_each(|card| {
+    ///     if card.rank.is_face() {
+    ///         card.theme.suits(SuitTheme::two_color())
+    ///     } else {
+    ///         card.theme
+    ///     }
+    /// });
+    /// ```
+    pub fn style_each<F: FnMut(&Card) -> CardTheme>(&mut self, mut f: F) {
+        for card in &mut self.cards {
+            *card = card.with_style_fn(&mut f);
+        }
+    }
+
+    /// Sorts the deck back into the order of [`Deck::standard`]: by suit and then by rank, with
+    /// the Ace low.
+    ///
+    /// The sort is stable, so duplicated cards keep their order. Presentation options such as the
+    /// size are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Deck;
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.cut(20);
+    /// assert!(!deck.is_sorted());
+    /// deck.sort();
+    /// assert_eq!(deck, Deck::standard());
+    /// ```
+    pub fn sort(&mut self) {
+        self.sort_with(SuitOrder::Declaration);
+    }
+
+    /// Returns true if the deck is in the order of [`Deck::standard`], which [`Deck::sort`]
+    /// restores.
+    pub fn is_sorted(&self) -> bool {
+        self.is_sorted_with(SuitOrder::Declaration)
+    }
+
+    /// Sorts the deck by suit in the given order, and then by rank with the Ace low. Jokers go to
+    /// the bottom, red before black.
+    ///
+    /// The sort is stable, so duplicated cards keep their order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Deck, Suit, SuitOrder};
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.sort_with(SuitOrder::Bridge);
+    /// assert_eq!(deck.cards()[0].suit, Suit::Clubs);
+    /// assert!(deck.is_sorted_with(SuitOrder::Bridge));
+    /// ```
+    pub fn sort_with(&mut self, order: SuitOrder) {
+        self.cards.sort_by_key(|card| sort_key(card, order));
+    }
+
+    /// Returns true if the deck is in the order that [`Deck::sort_with`] sorts it into.
+    pub fn is_sorted_with(&self, order: SuitOrder) -> bool {
+        self.cards.is_sorted_by_key(|card| sort_key(card, order))
+    }
+
+    /// Returns true if any card appears more than once in the deck.
+    ///
+    /// Cards are compared by rank and suit, and jokers by color, as with [`Card::same_card`].
+    pub fn has_duplicates(&self) -> bool {
+        self.first_duplicate().is_some()
+    }
+
+    /// Checks that the deck contains exactly one of each of the [`STANDARD_DECK_SIZE`] cards, in
+    /// any order, and nothing else.
+    ///
+    /// This is useful after importing a deck, e.g. from strings, to ensure its integrity. The first
+    /// joker (from the top of the deck) is reported first, as jokers are not part of a standard
+    /// deck, then the first duplicated card, and then any missing card.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Deck, DeckError, JokerColor, Rank, Suit};
+    ///
+    /// assert_eq!(Deck::standard().validate_standard(), Ok(()));
+    ///
+    /// let (_, deck) = Deck::standard().split_at(1);
+    /// assert_eq!(
+    ///     deck.validate_standard(),
+    ///     Err(DeckError::Missing(Rank::Ace, Suit::Spades))
+    /// );
+    ///
+    /// let deck = Deck::with_jokers();
+    /// assert_eq!(
+    ///     deck.validate_standard(),
+    ///     Err(DeckError::Extra(JokerColor::Red))
+    /// );
+    /// ```
+    pub fn validate_standard(&self) -> Result<(), DeckError> {
+        // every rank and suit is part of a standard deck, so only jokers can be extra
+        if let Some(color) = self.cards.iter().find_map(|card| card.joker) {
+            return Err(DeckError::Extra(color));
+        }
+        if let Some(card) = self.first_duplicate() {
+            return Err(DeckError::Duplicate(card.rank, card.suit));
+        }
+        let present: HashSet<_> = self
+            .cards
+            .iter()
+            .map(|card| (card.rank, card.suit, card.joker))
+            .collect();
+        match Self::standard()
+            .cards
+            .into_iter()
+            .find(|card| !present.contains(&(card.rank, card.suit, card.joker)))
+        {
+            Some(card) => Err(DeckError::Missing(card.rank, card.suit)),
+            None => Ok(()),
+        }
+    }
+
+    fn first_duplicate(&self) -> Option<&Card> {
+        let mut seen = HashSet::with_capacity(self.cards.len());
+        self.cards
+            .iter()
+            .find(|card| !seen.insert((card.rank, card.suit, card.joker)))
+    }
+
+    /// Cuts the deck: the top `at` cards are moved to the bottom, keeping their order.
+    ///
+    /// `at` is clamped to the number of cards, so cutting at `0` or at (or past) the end leaves
+    /// the deck unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Deck, Rank, Suit};
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.cut(13);
+    /// assert_eq!(deck.cards()[0], Card::new(Rank::Ace, Suit::Hearts));
+    /// assert_eq!(deck.cards()[51], Card::new(Rank::King, Suit::Spades));
+    /// ```
+    pub fn cut(&mut self, at: usize) {
+        let at = at.min(self.cards.len());
+        self.cards.rotate_left(at);
+    }
+
+    /// Reverses the order of the cards, so the bottom card becomes the top.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Deck, Rank, Suit};
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.reverse();
+    /// assert_eq!(deck.cards()[0], Card::new(Rank::King, Suit::Clubs));
+    /// ```
+    pub fn reverse(&mut self) {
+        self.cards.reverse();
+    }
+
+    /// Rotates the deck so that the top `n` cards move to the bottom, keeping their order.
+    ///
+    /// Unlike [`Deck::cut`], `n` wraps around the number of cards, so rotating a 52 card deck by
+    /// 53 is the same as rotating it by 1. Rotating an empty deck does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Deck, Rank, Suit};
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.rotate_left(1);
+    /// assert_eq!(deck.cards()[0], Card::new(Rank::Two, Suit::Spades));
+    /// assert_eq!(deck.cards()[51], Card::new(Rank::Ace, Suit::Spades));
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        if let Some(n) = n.checked_rem(self.cards.len()) {
+            self.cards.rotate_left(n);
+        }
+    }
+
+    /// Rotates the deck so that the bottom `n` cards move to the top, keeping their order.
+    ///
+    /// This undoes [`Deck::rotate_left`] by the same amount. `n` wraps around the number of
+    /// cards, and rotating an empty deck does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Deck, Rank, Suit};
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.rotate_right(1);
+    /// assert_eq!(deck.cards()[0], Card::new(Rank::King, Suit::Clubs));
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        if let Some(n) = n.checked_rem(self.cards.len()) {
+            self.cards.rotate_right(n);
+        }
+    }
+
+    /// Shuffles the deck with the given random number generator, giving every order of the cards
+    /// the same chance.
+    ///
+    /// This is a Fisher-Yates shuffle, so shuffling with a generator seeded the same way always
+    /// gives the same order, e.g. for replayable games or tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use tui_cards::Deck;
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.shuffle(&mut StdRng::seed_from_u64(42));
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn shuffle<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        for index in (1..self.cards.len()).rev() {
+            let other = rng.random_range(0..=index);
+            self.cards.swap(index, other);
+        }
+    }
+
+    /// Shuffles the deck with the thread local random number generator.
+    ///
+    /// See [`Deck::shuffle`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Deck;
+    ///
+    /// let mut deck = Deck::standard();
+    /// deck.shuffle_thread_rng();
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn shuffle_thread_rng(&mut self) {
+        self.shuffle(&mut rand::rng());
+    }
+
+    /// Splits the deck in two: the top `at` cards and the rest.
+    ///
+    /// `at` is clamped to the number of cards, so splitting at (or past) the end returns the whole
+    /// deck and an empty one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Deck;
+    ///
+    /// let (top, bottom) = Deck::standard().split_at(20);
+    /// assert_eq!(top.len(), 20);
+    /// assert_eq!(bottom.len(), 32);
+    /// ```
+    #[must_use]
+    pub fn split_at(mut self, at: usize) -> (Self, Self) {
+        let at = at.min(self.cards.len());
+        let bottom = self.cards.split_off(at);
+        (self, Self { cards: bottom })
+    }
+}
+
+/// Returns the key that [`Deck::sort_with`] sorts cards by, which places jokers after every
+/// regular card.
+fn sort_key(card: &Card, order: SuitOrder) -> (Option<JokerColor>, u8, Rank) {
+    (card.joker, order.position(card.suit), card.rank)
+}
+
+/// Lays the cards out in a grid within the area, as described by [`Deck::layout_in`].
+pub(crate) fn grid_layout(
+    cards: &[Card],
+    area: Rect,
+    size: CardSize,
+    gap: u16,
+) -> impl Iterator<Item = (Card, Rect)> + '_ {
+    let Size { width, height } = size.dimensions();
+    let step_x = width.saturating_add(gap);
+    let step_y = height.saturating_add(gap);
+    let columns = usize::from(area.width.saturating_add(gap) / step_x);
+    let rows = usize::from(area.height.saturating_add(gap) / step_y);
+    cards
+        .iter()
+        .take(columns * rows)
+        .enumerate()
+        .map(move |(index, card)| {
+            // both fit in a u16 as the cards are within the area
+            let column = (index % columns) as u16;
+            let row = (index / columns) as u16;
+            let x = area.x + column * step_x;
+            let y = area.y + row * step_y;
+            (card.size(size), Rect::new(x, y, width, height))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_same_cards(deck: &Deck) {
+        let mut cards = deck.cards().to_vec();
+        cards.sort_by_key(|card| (card.suit, card.rank));
+        assert_eq!(cards, Deck::standard().cards());
+    }
+
+    #[test]
+    fn standard_deck() {
+        let deck = Deck::standard();
+        assert_eq!(deck.len(), STANDARD_DECK_SIZE);
+        assert_eq!(deck.cards()[0], Card::new(Rank::Ace, Suit::Spades));
+        assert_eq!(deck.cards()[51], Card::new(Rank::King, Suit::Clubs));
+    }
+
+    #[test]
+    fn standard_deck_size_matches_enums() {
+        assert_eq!(STANDARD_DECK_SIZE, 52);
+        assert_eq!(
+            Rank::iter().count() * Suit::iter().count(),
+            STANDARD_DECK_SIZE
+        );
+        assert_eq!(Rank::ALL.len(), Rank::COUNT);
+    }
+
+    #[test]
+    fn validate_standard_deck() {
+        let mut deck = Deck::standard();
+        deck.cut(17);
+        assert!(!deck.has_duplicates());
+        assert_eq!(deck.validate_standard(), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_duplicate() {
+        let queen = Card::new(Rank::Queen, Suit::Hearts);
+        let deck = Deck::new(Deck::standard().cards().iter().copied().chain([queen]));
+        assert!(deck.has_duplicates());
+        assert_eq!(
+            deck.validate_standard(),
+            Err(DeckError::Duplicate(Rank::Queen, Suit::Hearts))
+        );
+    }
+
+    #[test]
+    fn validate_reports_missing() {
+        let two = Card::new(Rank::Two, Suit::Clubs);
+        let deck = Deck::new(
+            Deck::standard()
+                .cards()
+                .iter()
+                .copied()
+                .filter(|card| *card != two),
+        );
+        assert!(!deck.has_duplicates());
+        assert_eq!(
+            deck.validate_standard(),
+            Err(DeckError::Missing(Rank::Two, Suit::Clubs))
+        );
+        assert_eq!(
+            Deck::default().validate_standard(),
+            Err(DeckError::Missing(Rank::Ace, Suit::Spades))
+        );
+    }
+
+    #[test]
+    fn validate_reports_extra() {
+        let deck = Deck::with_jokers();
+        assert!(!deck.has_duplicates());
+        assert_eq!(
+            deck.validate_standard(),
+            Err(DeckError::Extra(JokerColor::Red))
+        );
+        // extra cards are reported before missing ones
+        let deck = Deck::new([Card::joker(JokerColor::Black, CardSize::Small)]);
+        assert_eq!(
+            deck.validate_standard(),
+            Err(DeckError::Extra(JokerColor::Black))
+        );
+        // and before duplicates, even of the joker itself
+        let joker = Card::joker(JokerColor::Red, CardSize::Normal);
+        let deck = Deck::new(
+            [Card::new(Rank::Ace, Suit::Spades); 2]
+                .into_iter()
+                .chain([joker; 2]),
+        );
+        assert_eq!(
+            deck.validate_standard(),
+            Err(DeckError::Extra(JokerColor::Red))
+        );
+    }
+
+    #[test]
+    fn duplicates_ignore_presentation() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let deck = Deck::new([ace, ace.size(crate::CardSize::Small)]);
+        assert!(deck.has_duplicates());
+    }
+
+    #[test]
+    fn style_each_styles_every_card() {
+        let mut deck = Deck::standard();
+        let theme = CardTheme::new().suits(crate::SuitTheme::two_color());
+        deck.style_each(|card| {
+            if card.suit == Suit::Clubs {
+                theme
+            } else {
+                card.theme
+            }
+        });
+        let styled = deck.cards().iter().filter(|card| card.theme == theme);
+        assert!(styled.map(|card| card.suit).eq([Suit::Clubs; 13]));
+        // styling does not change which cards are in the deck
+        assert_eq!(deck.validate_standard(), Ok(()));
+    }
+
+    #[test]
+    fn from_parts_is_the_cartesian_product() {
+        let deck = Deck::from_parts(
+            &[Rank::Ace, Rank::King],
+            &[Suit::Clubs, Suit::Hearts],
+            CardSize::Compact,
+        )
+        .unwrap();
+        let card = |rank, suit| Card::new(rank, suit).size(CardSize::Compact);
+        assert_eq!(
+            deck.cards(),
+            [
+                card(Rank::Ace, Suit::Clubs),
+                card(Rank::King, Suit::Clubs),
+                card(Rank::Ace, Suit::Hearts),
+                card(Rank::King, Suit::Hearts),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_parts_matches_standard() {
+        let deck = Deck::from_parts(
+            &Rank::ALL,
+            &[Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs],
+            CardSize::Normal,
+        );
+        assert_eq!(deck, Ok(Deck::standard()));
+    }
+
+    #[test]
+    fn from_parts_rejects_empty_and_duplicate_parts() {
+        let size = CardSize::Normal;
+        assert_eq!(
+            Deck::from_parts(&[], &[Suit::Spades], size),
+            Err(DeckError::NoParts)
+        );
+        assert_eq!(
+            Deck::from_parts(&Rank::ALL, &[], size),
+            Err(DeckError::NoParts)
+        );
+        assert_eq!(
+            Deck::from_parts(&[Rank::Ace], &[Suit::Spades, Suit::Spades], size),
+            Err(DeckError::Duplicate(Rank::Ace, Suit::Spades))
+        );
+    }
+
+    #[test]
+    fn sort_restores_standard_order() {
+        let mut deck = Deck::standard();
+        deck.cut(7);
+        let (top, bottom) = deck.split_at(30);
+        let mut deck = Deck::new(bottom.cards().iter().chain(top.cards()).rev().copied());
+        assert!(!deck.is_sorted());
+        deck.sort();
+        assert!(deck.is_sorted());
+        assert_eq!(deck, Deck::standard());
+    }
+
+    #[test]
+    fn is_sorted_edge_cases() {
+        assert!(Deck::default().is_sorted());
+        assert!(Deck::standard().is_sorted());
+        let two = Card::new(Rank::Two, Suit::Spades);
+        assert!(Deck::new([two, two]).is_sorted());
+        // the Ace is low
+        assert!(!Deck::new([
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Ace, Suit::Spades)
+        ])
+        .is_sorted());
+    }
+
+    #[test]
+    fn sort_with_orders_suits() {
+        let alternating = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
+        for order in [
+            SuitOrder::Declaration,
+            SuitOrder::Bridge,
+            SuitOrder::Alphabetical,
+            SuitOrder::Custom(alternating),
+        ] {
+            let mut deck = Deck::standard();
+            deck.cut(20);
+            deck.sort_with(order);
+            assert!(deck.is_sorted_with(order), "{order:?}");
+            let suits: Vec<_> = deck.cards().iter().map(|card| card.suit).dedup().collect();
+            let mut expected = suits.clone();
+            expected.sort_by_key(|suit| order.position(*suit));
+            assert_eq!(suits, expected, "{order:?}");
+            assert_eq!(deck.cards()[0].rank, Rank::Ace);
+        }
+        let mut deck = Deck::standard();
+        deck.sort_with(SuitOrder::Custom(alternating));
+        assert_eq!(deck.cards()[26].suit, Suit::Clubs);
+        assert!(!deck.is_sorted());
+    }
+
+    #[test]
+    fn layout_in_wraps_rows() {
+        let deck = Deck::standard();
+        let area = Rect::new(2, 3, 17, 11);
+        let layout: Vec<_> = deck.layout_in(area, CardSize::Small, 1).collect();
+        let areas: Vec<_> = layout.iter().map(|(_, area)| *area).collect();
+        assert_eq!(
+            areas,
+            [
+                Rect::new(2, 3, 8, 5),
+                Rect::new(11, 3, 8, 5),
+                Rect::new(2, 9, 8, 5),
+                Rect::new(11, 9, 8, 5),
+            ]
+        );
+        assert_eq!(
+            layout[0].0,
+            Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small)
+        );
+        assert_eq!(layout[3].0.rank, Rank::Four);
+    }
+
+    #[test]
+    fn layout_in_stops_when_out_of_space() {
+        let deck = Deck::standard();
+        assert_eq!(
+            deck.layout_in(Rect::new(0, 0, 7, 5), CardSize::Small, 0)
+                .count(),
+            0
+        );
+        assert_eq!(
+            deck.layout_in(Rect::new(0, 0, 100, 100), CardSize::Glyph, 0)
+                .count(),
+            52
+        );
+        assert_eq!(deck.layout_in(Rect::ZERO, CardSize::Glyph, 3).count(), 0);
+    }
+
+    #[test]
+    fn deck_error_display() {
+        assert_eq!(
+            DeckError::Duplicate(Rank::Ace, Suit::Spades).to_string(),
+            "duplicate card: Ace of Spades"
+        );
+        assert_eq!(
+            DeckError::Missing(Rank::Ace, Suit::Spades).to_string(),
+            "missing card: Ace of Spades"
+        );
+        assert_eq!(
+            DeckError::Extra(JokerColor::Red).to_string(),
+            "not a standard card: Red Joker"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut deck = Deck::standard();
+        deck.cut(5);
+        let json = serde_json::to_string(&deck).unwrap();
+        assert_eq!(serde_json::from_str::<Deck>(&json).unwrap(), deck);
+    }
+
+    #[test]
+    fn cut_swaps_halves() {
+        let mut deck = Deck::standard();
+        deck.cut(10);
+        assert_eq!(deck.cards()[..42], Deck::standard().cards()[10..]);
+        assert_eq!(deck.cards()[42..], Deck::standard().cards()[..10]);
+        assert_same_cards(&deck);
+    }
+
+    #[test]
+    fn cut_is_clamped() {
+        for at in [0, STANDARD_DECK_SIZE, 100] {
+            let mut deck = Deck::standard();
+            deck.cut(at);
+            assert_eq!(deck, Deck::standard());
+        }
+        let mut empty = Deck::default();
+        empty.cut(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn with_jokers_adds_one_of_each_color() {
+        let deck = Deck::with_jokers();
+        assert_eq!(deck.len(), STANDARD_DECK_SIZE + 2);
+        assert_eq!(
+            deck.cards()[..STANDARD_DECK_SIZE],
+            *Deck::standard().cards()
+        );
+        let colors: Vec<_> = deck.cards()[STANDARD_DECK_SIZE..]
+            .iter()
+            .map(|card| card.joker)
+            .collect();
+        assert_eq!(colors, [Some(JokerColor::Red), Some(JokerColor::Black)]);
+        assert!(!deck.has_duplicates());
+        let mut shuffled = deck.clone();
+        shuffled.reverse();
+        shuffled.sort();
+        assert_eq!(
+            shuffled.cards()[..STANDARD_DECK_SIZE],
+            *Deck::standard().cards()
+        );
+        assert!(shuffled.cards()[STANDARD_DECK_SIZE..]
+            .iter()
+            .is_sorted_by_key(|card| card.joker));
+    }
+
+    #[test]
+    fn deal_takes_from_the_top() {
+        let mut deck = Deck::with_jokers();
+        deck.set_size(CardSize::Small);
+        let mut dealt = Vec::new();
+        while let Some(card) = deck.deal() {
+            assert_eq!(card.size, CardSize::Small);
+            dealt.push(card.size(CardSize::Normal));
+        }
+        assert_eq!(deck.remaining(), 0);
+        assert_eq!(dealt, Deck::with_jokers().cards());
+        assert_eq!(deck.deal(), None);
+    }
+
+    #[test]
+    fn jokers_do_not_stand_in_for_kings() {
+        let mut cards = Deck::standard().cards().to_vec();
+        let king = cards
+            .iter()
+            .position(|card| *card == Card::new(Rank::King, Suit::Hearts));
+        let joker = Card::joker(JokerColor::Red, CardSize::Normal);
+        cards[king.unwrap()] = joker;
+        let deck = Deck::new(cards);
+        assert!(!deck.has_duplicates());
+        assert_eq!(
+            deck.validate_standard(),
+            Err(DeckError::Extra(JokerColor::Red))
+        );
+    }
+
+    #[test]
+    fn reverse_twice_restores_order() {
+        let mut deck = Deck::standard();
+        deck.reverse();
+        assert_eq!(deck.cards().first(), Deck::standard().cards().last());
+        assert_same_cards(&deck);
+        deck.reverse();
+        assert_eq!(deck, Deck::standard());
+    }
+
+    #[test]
+    fn rotate_wraps_around() {
+        let mut deck = Deck::standard();
+        deck.rotate_left(STANDARD_DECK_SIZE + 3);
+        assert_eq!(deck.cards()[..49], Deck::standard().cards()[3..]);
+        assert_same_cards(&deck);
+        deck.rotate_right(3);
+        assert_eq!(deck, Deck::standard());
+        deck.rotate_right(STANDARD_DECK_SIZE);
+        assert_eq!(deck, Deck::standard());
+        let mut empty = Deck::default();
+        empty.rotate_left(2);
+        empty.rotate_right(2);
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn shuffle_is_seeded() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let shuffled = |seed| {
+            let mut deck = Deck::standard();
+            deck.shuffle(&mut StdRng::seed_from_u64(seed));
+            deck
+        };
+        let deck = shuffled(7);
+        assert_same_cards(&deck);
+        assert_ne!(deck, Deck::standard());
+        assert_eq!(shuffled(7), deck);
+        assert_ne!(shuffled(8), deck);
+        let mut empty = Deck::default();
+        empty.shuffle_thread_rng();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn split_at_preserves_cards() {
+        let (top, bottom) = Deck::standard().split_at(20);
+        assert_eq!(top.cards(), &Deck::standard().cards()[..20]);
+        assert_eq!(bottom.cards(), &Deck::standard().cards()[20..]);
+        let rejoined = Deck::new(top.cards().iter().chain(bottom.cards()).copied());
+        assert_eq!(rejoined, Deck::standard());
+    }
+
+    #[test]
+    fn peek_is_clamped() {
+        let deck = Deck::standard();
+        assert_eq!(deck.peek(0), &[]);
+        assert_eq!(deck.peek(3), &deck.cards()[..3]);
+        assert_eq!(deck.peek(100), deck.cards());
+        assert_eq!(deck.peek_top(), Some(&Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(Deck::default().peek(1), &[]);
+        assert_eq!(Deck::default().peek_top(), None);
+    }
+
+    #[test]
+    fn split_at_is_clamped() {
+        let (top, bottom) = Deck::standard().split_at(60);
+        assert_eq!(top, Deck::standard());
+        assert!(bottom.is_empty());
+        let (top, bottom) = Deck::standard().split_at(0);
+        assert!(top.is_empty());
+        assert_eq!(bottom, Deck::standard());
+    }
+}
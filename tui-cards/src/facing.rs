@@ -0,0 +1,380 @@
+use std::error::Error;
+use std::fmt;
+
+use ratatui_core::layout::Size;
+use ratatui_core::style::{Color, Style};
+use unicode_width::UnicodeWidthChar;
+
+use crate::CardSize;
+
+/// Which side of a [`Card`] is shown.
+///
+/// [`Card`]: crate::Card
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Facing {
+    /// The rank and suit are shown.
+    #[default]
+    Up,
+    /// The back of the card is shown, hiding its rank and suit.
+    Down,
+}
+
+/// The pattern on the back of a face down [`Card`].
+///
+/// The back is a small tile of symbols repeated across the inside of the card's border, starting
+/// from the top left. Compact cards, which have no border, repeat the first row of the tile above
+/// their underline. The tile must fit inside the border of a [`CardSize::Small`] card, the
+/// smallest card with a border, so it is at most [`CardBack::MAX_WIDTH`] columns by
+/// [`CardBack::MAX_HEIGHT`] rows, and it is made of single column symbols so that it lines up.
+///
+/// The default back is a diagonal hatch.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Style, Stylize};
+/// use tui_cards::{Card, CardBack, Facing, Rank, Suit};
+///
+/// let back = CardBack::new("╲╱\n╱╲")?.style(Style::new().red());
+/// let card = Card::new(Rank::Ace, Suit::Spades)
+///     .facing(Facing::Down)
+///     .back(back);
+/// # Ok::<(), tui_cards::CardBackError>(())
+/// ```
+///
+/// [`Card`]: crate::Card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCardBack"))]
+pub struct CardBack {
+    tile: [[char; CardBack::MAX_WIDTH]; CardBack::MAX_HEIGHT],
+    width: u8,
+    height: u8,
+    style: Style,
+}
+
+/// An error returned when a tile cannot be used for a [`CardBack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CardBackError {
+    /// The tile has no rows, or a row with no symbols.
+    Empty,
+    /// The rows of the tile have different numbers of symbols.
+    Uneven,
+    /// The tile does not fit inside the border of a small card.
+    TooLarge {
+        /// The number of columns in the tile.
+        width: usize,
+        /// The number of rows in the tile.
+        height: usize,
+    },
+    /// The symbol does not occupy exactly one column.
+    NotSingleWidth(char),
+}
+
+impl fmt::Display for CardBackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the card back tile is empty"),
+            Self::Uneven => write!(f, "the rows of the card back tile differ in length"),
+            Self::TooLarge { width, height } => write!(
+                f,
+                "the card back tile is {width}x{height}, but at most {}x{} fits in a card",
+                CardBack::MAX_WIDTH,
+                CardBack::MAX_HEIGHT
+            ),
+            Self::NotSingleWidth(symbol) => {
+                write!(f, "{symbol:?} does not occupy exactly one column")
+            }
+        }
+    }
+}
+
+impl Error for CardBackError {}
+
+impl CardBack {
+    /// The most columns a tile can have: the width inside the border of a small card.
+    pub const MAX_WIDTH: usize = 6;
+
+    /// The most rows a tile can have: the height inside the border of a small card.
+    pub const MAX_HEIGHT: usize = 3;
+
+    /// Creates a back that repeats the given tile, with one line of the string per row.
+    ///
+    /// Returns an error if the tile is empty, its rows differ in length, it is larger than
+    /// [`CardBack::MAX_WIDTH`] by [`CardBack::MAX_HEIGHT`], or it has a symbol that is not one
+    /// column wide.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardBack, CardBackError};
+    ///
+    /// assert!(CardBack::new("┼─\n│ ").is_ok());
+    /// assert_eq!(CardBack::new("┼─\n│"), Err(CardBackError::Uneven));
+    /// ```
+    pub fn new(tile: &str) -> Result<Self, CardBackError> {
+        let rows: Vec<Vec<char>> = tile.lines().map(|row| row.chars().collect()).collect();
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 || rows.iter().any(Vec::is_empty) {
+            return Err(CardBackError::Empty);
+        }
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(CardBackError::Uneven);
+        }
+        if let Some(symbol) = rows
+            .iter()
+            .flatten()
+            .find(|symbol| symbol.width() != Some(1))
+        {
+            return Err(CardBackError::NotSingleWidth(*symbol));
+        }
+        let height = rows.len();
+        if width > Self::MAX_WIDTH || height > Self::MAX_HEIGHT {
+            return Err(CardBackError::TooLarge { width, height });
+        }
+        let mut back = Self::solid(' ');
+        for (row, symbols) in rows.iter().enumerate() {
+            back.tile[row][..width].copy_from_slice(symbols);
+        }
+        back.width = width as u8;
+        back.height = height as u8;
+        Ok(back)
+    }
+
+    /// Creates a back filled with a single symbol.
+    ///
+    /// The symbol must be one column wide, like the symbols of [`CardBack::new`]. This is not
+    /// checked here so that the back can be created in a `const`, but [`Card::validate`] reports a
+    /// wider symbol, which would push the card's right border out of place.
+    ///
+    /// [`Card::validate`]: crate::Card::validate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::CardBack;
+    ///
+    /// let back = CardBack::solid('░');
+    /// ```
+    pub const fn solid(symbol: char) -> Self {
+        // the unused cells are blank, as for a tile from `CardBack::new`, so equal backs compare
+        // equal however they were created
+        let mut tile = [[' '; Self::MAX_WIDTH]; Self::MAX_HEIGHT];
+        tile[0][0] = symbol;
+        Self {
+            tile,
+            width: 1,
+            height: 1,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the style the back is drawn in, applied on top of the theme's
+    /// [back style](crate::CardTheme::back_style).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use tui_cards::CardBack;
+    ///
+    /// let back = CardBack::solid('░').style(Style::new().green());
+    /// ```
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the style the back is drawn in on top of the theme's back style.
+    pub const fn back_style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the first symbol of the tile that does not occupy exactly one column, if any.
+    pub(crate) fn wide_symbol(&self) -> Option<char> {
+        self.tile[..usize::from(self.height)]
+            .iter()
+            .flat_map(|row| &row[..usize::from(self.width)])
+            .copied()
+            .find(|symbol| symbol.width() != Some(1))
+    }
+
+    /// Returns the symbol at the given column and row inside the border, repeating the tile.
+    const fn symbol_at(&self, column: usize, row: usize) -> char {
+        self.tile[row % self.height as usize][column % self.width as usize]
+    }
+}
+
+/// The fields of a serialized [`CardBack`], checked by [`CardBack::new`] before they are used.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCardBack {
+    tile: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+    style: Style,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCardBack> for CardBack {
+    type Error = CardBackError;
+
+    fn try_from(raw: RawCardBack) -> Result<Self, Self::Error> {
+        let RawCardBack {
+            tile,
+            width,
+            height,
+            style,
+        } = raw;
+        if width > Self::MAX_WIDTH || height > Self::MAX_HEIGHT {
+            return Err(CardBackError::TooLarge { width, height });
+        }
+        let rows: Vec<String> = tile
+            .iter()
+            .take(height)
+            .map(|row| row.iter().take(width).collect())
+            .collect();
+        Ok(Self::new(&rows.join("\n"))?.style(style))
+    }
+}
+
+impl Default for CardBack {
+    fn default() -> Self {
+        Self::solid(BACK_FILL)
+    }
+}
+
+/// The color the back of a card is drawn in, unless it is tinted with its suit's color.
+pub(crate) const BACK_COLOR: Color = Color::Blue;
+
+/// The character the back of a card is filled with.
+const BACK_FILL: char = '╱';
+
+/// The Unicode playing card back character, used for face down [`CardSize::Glyph`] cards.
+pub(crate) const BACK_GLYPH: char = '\u{1F0A0}';
+
+/// Returns the symbol at the given column and row of the back of a card of the given size: the
+/// card's border filled with the back's tile.
+///
+/// Compact cards have no border, so they are filled with the first row of the tile above their
+/// underline, and tiny cards are a single row of the tile.
+pub(crate) fn back_symbol(size: CardSize, back: &CardBack, column: u16, row: u16) -> char {
+    let Size { width, height } = size.dimensions();
+    let right = usize::from(width.saturating_sub(1));
+    let bottom = usize::from(height.saturating_sub(1));
+    let (column, row) = (usize::from(column), usize::from(row));
+    match size {
+        CardSize::Tiny => back.symbol_at(column, 0),
+        CardSize::Compact if row == 0 => back.symbol_at(column, 0),
+        CardSize::Compact => '─',
+        _ => match (column, row) {
+            (0, 0) => '╭',
+            (column, 0) if column == right => '╮',
+            (0, row) if row == bottom => '╰',
+            (column, row) if column == right && row == bottom => '╯',
+            (_, row) if row == 0 || row == bottom => '─',
+            (column, _) if column == 0 || column == right => '│',
+            (column, row) => back.symbol_at(column - 1, row - 1),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+    use unicode_width::UnicodeWidthStr;
+
+    use super::*;
+
+    /// Returns the lines of the back of a card of the given size.
+    fn back_lines(size: CardSize, back: &CardBack) -> Vec<String> {
+        let Size { width, height } = size.dimensions();
+        (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|column| back_symbol(size, back, column, row))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn back_lines_match_dimensions() {
+        for size in CardSize::iter().filter(|size| *size != CardSize::Glyph) {
+            let Size { width, height } = size.dimensions();
+            let lines = back_lines(size, &CardBack::default());
+            assert_eq!(lines.len(), usize::from(height), "{size:?}");
+            for line in lines {
+                assert_eq!(line.width(), usize::from(width), "{size:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn small_back() {
+        assert_eq!(
+            back_lines(CardSize::Small, &CardBack::default()),
+            ["╭──────╮", "│╱╱╱╱╱╱│", "│╱╱╱╱╱╱│", "│╱╱╱╱╱╱│", "╰──────╯"]
+        );
+    }
+
+    #[test]
+    fn tiled_back() {
+        let back = CardBack::new("╲╱\n╱╲").unwrap();
+        assert_eq!(
+            back_lines(CardSize::Small, &back),
+            ["╭──────╮", "│╲╱╲╱╲╱│", "│╱╲╱╲╱╲│", "│╲╱╲╱╲╱│", "╰──────╯"]
+        );
+        assert_eq!(back_lines(CardSize::Compact, &back)[0], "╲╱╲╱╲╱╲╱");
+        let solid = CardBack::solid('░');
+        assert_eq!(back_lines(CardSize::Small, &solid)[2], "│░░░░░░│");
+    }
+
+    #[test]
+    fn card_back_tile_must_fit() {
+        assert_eq!(CardBack::new(""), Err(CardBackError::Empty));
+        assert_eq!(CardBack::new("ab\n\ncd"), Err(CardBackError::Empty));
+        assert_eq!(CardBack::new("ab\nc"), Err(CardBackError::Uneven));
+        assert_eq!(
+            CardBack::new("中"),
+            Err(CardBackError::NotSingleWidth('中'))
+        );
+        assert_eq!(
+            CardBack::new("1234567"),
+            Err(CardBackError::TooLarge {
+                width: 7,
+                height: 1
+            })
+        );
+        assert_eq!(
+            CardBack::new("a\nb\nc\nd"),
+            Err(CardBackError::TooLarge {
+                width: 1,
+                height: 4
+            })
+        );
+        assert!(CardBack::new("123456\nabcdef\nABCDEF").is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_checks_tile() {
+        let back = CardBack::new("╲╱\n╱╲").unwrap().style(Style::new().red());
+        let json = serde_json::to_string(&back).unwrap();
+        assert_eq!(serde_json::from_str::<CardBack>(&json).unwrap(), back);
+        let default = serde_json::to_string(&CardBack::default()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CardBack>(&default).unwrap(),
+            CardBack::default()
+        );
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["width"] = 0.into();
+        assert!(serde_json::from_value::<CardBack>(value.clone()).is_err());
+        value["width"] = 2.into();
+        value["height"] = 4.into();
+        assert!(serde_json::from_value::<CardBack>(value).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+//! Move legality helpers shared by many card games.
+
+use crate::{AceOrder, Card, Suit};
+
+/// A rule deciding whether a card may be placed on top of another card.
+///
+/// Aces are low, so an Ace is one below a Two and a King is the highest rank. Ranks do not wrap
+/// around. Jokers have no rank or suit, so no rule allows a joker to be placed, or a card to be
+/// placed on a joker. Games where jokers are wild need their own rules.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{Card, Rank, StackRule, Suit};
+///
+/// let six = Card::new(Rank::Six, Suit::Hearts);
+/// let seven = Card::new(Rank::Seven, Suit::Clubs);
+/// assert!(six.is_playable_on(&seven, StackRule::AlternatingColorDescending));
+/// assert!(!six.is_playable_on(&seven, StackRule::SameSuitAscending));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StackRule {
+    /// One rank lower and the opposite color, like a solitaire tableau.
+    AlternatingColorDescending,
+    /// One rank lower, in any suit.
+    AnySuitDescending,
+    /// One rank lower and the same suit.
+    SameSuitDescending,
+    /// One rank higher and the same suit, like a solitaire foundation.
+    SameSuitAscending,
+}
+
+impl StackRule {
+    /// Returns true if `top` may be placed on `onto` under this rule.
+    ///
+    /// Always false if either card is a joker.
+    pub fn allows(self, top: &Card, onto: &Card) -> bool {
+        if top.is_joker() || onto.is_joker() {
+            return false;
+        }
+        let top_rank = top.rank.value(AceOrder::Low);
+        let onto_rank = onto.rank.value(AceOrder::Low);
+        let descending = top_rank.checked_add(1) == Some(onto_rank);
+        let ascending = onto_rank.checked_add(1) == Some(top_rank);
+        match self {
+            Self::AlternatingColorDescending => {
+                descending && top.suit.is_red() != onto.suit.is_red()
+            }
+            Self::AnySuitDescending => descending,
+            Self::SameSuitDescending => descending && top.suit == onto.suit,
+            Self::SameSuitAscending => ascending && top.suit == onto.suit,
+        }
+    }
+}
+
+impl Card {
+    /// Returns true if this card may be placed on top of `onto` under the given rule.
+    ///
+    /// Only the rank and suit are considered; presentation options are ignored. Jokers are never
+    /// playable, and nothing is playable on a joker.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Rank, StackRule, Suit};
+    ///
+    /// let ace = Card::new(Rank::Ace, Suit::Spades);
+    /// let two = Card::new(Rank::Two, Suit::Spades);
+    /// assert!(two.is_playable_on(&ace, StackRule::SameSuitAscending));
+    /// ```
+    pub fn is_playable_on(&self, onto: &Self, rule: StackRule) -> bool {
+        rule.allows(self, onto)
+    }
+}
+
+/// Returns the index of the card that wins a trick, or `None` if no card follows the led suit or
+/// is a trump.
+///
+/// The highest trump wins if any trumps were played. Otherwise the highest card of the led suit
+/// wins, and cards of other suits can never win. Aces are high, as in most trick-taking games, and
+/// if the same card was played twice the first one wins. Jokers have no suit, so they neither
+/// follow the led suit nor count as trumps, and never win. Games with unusual trump ranks, such as
+/// the bowers in Euchre, or with jokers that beat other cards, need their own rules.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{trick_winner, Card, Rank, Suit};
+///
+/// let trick = [
+///     Card::new(Rank::King, Suit::Hearts),
+///     Card::new(Rank::Ace, Suit::Hearts),
+///     Card::new(Rank::Two, Suit::Spades),
+///     Card::new(Rank::Ace, Suit::Clubs),
+/// ];
+/// assert_eq!(trick_winner(&trick, Suit::Hearts, None), Some(1));
+/// assert_eq!(trick_winner(&trick, Suit::Hearts, Some(Suit::Spades)), Some(2));
+/// ```
+pub fn trick_winner(cards: &[Card], led: Suit, trump: Option<Suit>) -> Option<usize> {
+    // a joker's suit is only a placeholder, so jokers take no part in the trick
+    let in_suit = |card: &Card, suit: Suit| !card.is_joker() && card.suit == suit;
+    let suit = trump
+        .filter(|trump| cards.iter().any(|card| in_suit(card, *trump)))
+        .unwrap_or(led);
+    cards
+        .iter()
+        .enumerate()
+        .filter(|(_, card)| in_suit(card, suit))
+        // reversed so that the first of two equal cards wins, as max_by_key picks the last
+        .rev()
+        .max_by_key(|(_, card)| card.rank.value(AceOrder::High))
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CardSize, JokerColor, Rank, Suit};
+
+    const fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(rank, suit)
+    }
+
+    #[test]
+    fn alternating_color_descending() {
+        let rule = StackRule::AlternatingColorDescending;
+        let black_eight = card(Rank::Eight, Suit::Spades);
+        assert!(card(Rank::Seven, Suit::Hearts).is_playable_on(&black_eight, rule));
+        assert!(card(Rank::Seven, Suit::Diamonds).is_playable_on(&black_eight, rule));
+        assert!(!card(Rank::Seven, Suit::Clubs).is_playable_on(&black_eight, rule));
+        assert!(!card(Rank::Six, Suit::Hearts).is_playable_on(&black_eight, rule));
+        assert!(!card(Rank::Nine, Suit::Hearts).is_playable_on(&black_eight, rule));
+        let red_king = card(Rank::King, Suit::Hearts);
+        assert!(card(Rank::Queen, Suit::Clubs).is_playable_on(&red_king, rule));
+    }
+
+    #[test]
+    fn any_suit_descending() {
+        let rule = StackRule::AnySuitDescending;
+        let ten = card(Rank::Ten, Suit::Clubs);
+        assert!(card(Rank::Nine, Suit::Clubs).is_playable_on(&ten, rule));
+        assert!(card(Rank::Nine, Suit::Hearts).is_playable_on(&ten, rule));
+        assert!(!card(Rank::Jack, Suit::Clubs).is_playable_on(&ten, rule));
+        assert!(!card(Rank::Ten, Suit::Hearts).is_playable_on(&ten, rule));
+    }
+
+    #[test]
+    fn same_suit_descending() {
+        let rule = StackRule::SameSuitDescending;
+        let two = card(Rank::Two, Suit::Diamonds);
+        assert!(card(Rank::Ace, Suit::Diamonds).is_playable_on(&two, rule));
+        assert!(!card(Rank::Ace, Suit::Hearts).is_playable_on(&two, rule));
+        assert!(!card(Rank::Three, Suit::Diamonds).is_playable_on(&two, rule));
+    }
+
+    #[test]
+    fn same_suit_ascending() {
+        let rule = StackRule::SameSuitAscending;
+        let queen = card(Rank::Queen, Suit::Spades);
+        assert!(card(Rank::King, Suit::Spades).is_playable_on(&queen, rule));
+        assert!(!card(Rank::King, Suit::Clubs).is_playable_on(&queen, rule));
+        assert!(!card(Rank::Jack, Suit::Spades).is_playable_on(&queen, rule));
+        // ranks do not wrap around
+        let king = card(Rank::King, Suit::Spades);
+        assert!(!card(Rank::Ace, Suit::Spades).is_playable_on(&king, rule));
+    }
+
+    #[test]
+    fn trick_winner_follows_led_suit_without_trump() {
+        let trick = [
+            card(Rank::Ten, Suit::Clubs),
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Queen, Suit::Clubs),
+            card(Rank::Two, Suit::Clubs),
+        ];
+        assert_eq!(trick_winner(&trick, Suit::Clubs, None), Some(2));
+        // a trump suit nobody played changes nothing
+        assert_eq!(
+            trick_winner(&trick, Suit::Clubs, Some(Suit::Hearts)),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn trick_winner_trump_beats_led_suit() {
+        let trick = [
+            card(Rank::Ace, Suit::Diamonds),
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Five, Suit::Hearts),
+            card(Rank::King, Suit::Diamonds),
+        ];
+        assert_eq!(
+            trick_winner(&trick, Suit::Diamonds, Some(Suit::Hearts)),
+            Some(2)
+        );
+        assert_eq!(trick_winner(&trick, Suit::Diamonds, None), Some(0));
+    }
+
+    #[test]
+    fn jokers_are_never_playable() {
+        let red_joker = Card::joker(JokerColor::Red, CardSize::Normal);
+        let black_joker = Card::joker(JokerColor::Black, CardSize::Normal);
+        let rules = [
+            StackRule::AlternatingColorDescending,
+            StackRule::AnySuitDescending,
+            StackRule::SameSuitDescending,
+            StackRule::SameSuitAscending,
+        ];
+        for rule in rules {
+            assert!(!card(Rank::Queen, Suit::Clubs).is_playable_on(&red_joker, rule));
+            assert!(!card(Rank::Queen, Suit::Spades).is_playable_on(&black_joker, rule));
+            assert!(!red_joker.is_playable_on(&card(Rank::Ace, Suit::Hearts), rule));
+            assert!(!black_joker.is_playable_on(&card(Rank::Queen, Suit::Spades), rule));
+        }
+    }
+
+    #[test]
+    fn jokers_never_win_tricks() {
+        let red_joker = Card::joker(JokerColor::Red, CardSize::Normal);
+        let trick = [card(Rank::Queen, Suit::Hearts), red_joker];
+        assert_eq!(trick_winner(&trick, Suit::Hearts, None), Some(0));
+        // a joker does not make its placeholder suit trumps
+        let trick = [card(Rank::Two, Suit::Clubs), red_joker];
+        assert_eq!(
+            trick_winner(&trick, Suit::Clubs, Some(Suit::Hearts)),
+            Some(0)
+        );
+        assert_eq!(trick_winner(&[red_joker], Suit::Hearts, None), None);
+    }
+
+    #[test]
+    fn trick_winner_edge_cases() {
+        assert_eq!(trick_winner(&[], Suit::Spades, Some(Suit::Hearts)), None);
+        let off_suit = [card(Rank::Ace, Suit::Clubs)];
+        assert_eq!(trick_winner(&off_suit, Suit::Spades, None), None);
+        let twice = [card(Rank::Ace, Suit::Spades), card(Rank::Ace, Suit::Spades)];
+        assert_eq!(trick_winner(&twice, Suit::Spades, None), Some(0));
+    }
+
+    #[test]
+    fn ignores_presentation() {
+        let rule = StackRule::SameSuitAscending;
+        let ace = card(Rank::Ace, Suit::Hearts).size(crate::CardSize::Small);
+        assert!(card(Rank::Two, Suit::Hearts).is_playable_on(&ace, rule));
+    }
+}
@@ -0,0 +1,179 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+use ratatui_core::widgets::Widget;
+
+use crate::deck::grid_layout;
+use crate::{Card, CardSize};
+
+/// The default number of columns and rows between two adjacent cards.
+const DEFAULT_GAP: u16 = 1;
+
+/// Cards laid out in rows, such as a tableau or a gallery of a whole deck.
+///
+/// Cards are placed left to right and then top to bottom, all at the grid's size, with the gap
+/// between them, in the same way as [`Deck::layout_in`]. Cards that do not fit entirely within the
+/// area are not drawn.
+///
+/// [`Deck::layout_in`]: crate::Deck::layout_in
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Style, Stylize};
+/// use tui_cards::{CardGrid, CardSize, Deck};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let grid = CardGrid::new(Deck::standard().cards().iter().copied())
+///     .size(CardSize::Small)
+///     .gap_style(Style::new().on_green());
+/// frame.render_widget(&grid, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardGrid {
+    /// The cards in the grid, in the order they are laid out.
+    pub cards: Vec<Card>,
+    /// The size every card is drawn at. Defaults to [`CardSize::Normal`].
+    pub size: CardSize,
+    /// The number of columns and rows between two adjacent cards. Defaults to 1.
+    pub gap: u16,
+    /// The style of the cells between the cards, or `None` to leave them as they are. Defaults to
+    /// `None`. See [`CardGrid::gap_style`].
+    pub gap_style: Option<Style>,
+}
+
+impl CardGrid {
+    /// Creates a grid of the given cards.
+    pub fn new<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        Self {
+            cards: cards.into_iter().collect(),
+            size: CardSize::Normal,
+            gap: DEFAULT_GAP,
+            gap_style: None,
+        }
+    }
+
+    /// Sets the size every card is drawn at.
+    #[must_use]
+    pub const fn size(mut self, size: CardSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the number of columns and rows between two adjacent cards.
+    #[must_use]
+    pub const fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the style of the cells of the area that no card covers, e.g. a green background for
+    /// the felt of a card table.
+    ///
+    /// By default these cells are left untouched, so whatever was rendered underneath shows
+    /// between the cards. The style is only applied to the gaps, so it does not bleed into the
+    /// cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use tui_cards::{Card, CardGrid, Rank, Suit};
+    ///
+    /// let grid = CardGrid::new([Card::new(Rank::Ace, Suit::Spades)])
+    ///     .gap_style(Style::new().on_green());
+    /// ```
+    #[must_use]
+    pub fn gap_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.gap_style = Some(style.into());
+        self
+    }
+}
+
+impl Default for CardGrid {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl Widget for &CardGrid {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        // the cells covered by a card, row by row, marked while drawing the cards so the gaps are
+        // found in a single pass over the area
+        let mut covered = vec![false; usize::from(area.width) * usize::from(area.height)];
+        for (card, bounds) in grid_layout(&self.cards, area, self.size, self.gap) {
+            card.render(bounds, buf);
+            for position in bounds.positions() {
+                let row = usize::from(position.y - area.y);
+                let column = usize::from(position.x - area.x);
+                covered[row * usize::from(area.width) + column] = true;
+            }
+        }
+        if let Some(style) = self.gap_style {
+            for (position, covered) in area.positions().zip(covered) {
+                if !covered {
+                    buf[position].set_style(style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Modifier};
+
+    use super::*;
+    use crate::{Rank, Suit};
+
+    fn grid() -> CardGrid {
+        CardGrid::new([
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Clubs),
+        ])
+        .size(CardSize::Small)
+    }
+
+    #[test]
+    fn lays_cards_out_in_rows() {
+        let grid = grid().gap(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 12));
+        grid.render(buf.area, &mut buf);
+        let mut expected = Buffer::empty(buf.area);
+        for (card, area) in grid_layout(&grid.cards, buf.area, CardSize::Small, 2) {
+            card.render(area, &mut expected);
+        }
+        assert_eq!(buf, expected);
+        // two cards fit on a row, so the third starts the next one
+        assert_eq!(buf[(11, 1)].symbol(), "2");
+        assert_eq!(buf[(1, 8)].symbol(), "3");
+    }
+
+    #[test]
+    fn gap_style_fills_cells_between_cards() {
+        let grid = grid().gap_style(Style::new().on_green().bold());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 11));
+        grid.render(buf.area, &mut buf);
+        assert_eq!(buf[(8, 0)].bg, Color::Green);
+        assert_eq!(buf[(18, 2)].bg, Color::Green);
+        assert_eq!(buf[(0, 5)].bg, Color::Green);
+        // the space left after the last card is a gap too
+        assert_eq!(buf[(12, 8)].bg, Color::Green);
+        // the cards keep their own style
+        assert_eq!(buf[(0, 0)].bg, Color::White);
+        assert_eq!(buf[(10, 1)].modifier, Modifier::empty());
+    }
+
+    #[test]
+    fn gaps_are_untouched_by_default() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+        grid().render(buf.area, &mut buf);
+        assert_eq!(buf[(8, 0)], Buffer::empty(buf.area)[(8, 0)]);
+    }
+}
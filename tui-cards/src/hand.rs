@@ -0,0 +1,1533 @@
+use std::collections::HashSet;
+use std::iter;
+
+use itertools::Itertools;
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::Style;
+use ratatui_core::text::Text;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{Card, CardSize, Facing, Rank, Suit, SuitOrder};
+
+/// The default number of columns between the left edges of two adjacent cards.
+const DEFAULT_SPACING: u16 = 4;
+
+/// The default number of rows a selected card is raised above the rest of the hand.
+const DEFAULT_LIFT: u16 = 1;
+
+/// The largest angle in degrees that [`Hand::fan_angle`] tilts cards by.
+const MAX_FAN_ANGLE: u8 = 45;
+
+/// The height of a terminal cell divided by its width, used to turn an angle into a shear.
+const CELL_ASPECT: f64 = 2.0;
+
+/// A hand of playing cards rendered as a horizontal fan.
+///
+/// Cards are drawn left to right, each overlapping the previous one so that only the left edge
+/// and corner index of every card but the last is visible. Headroom equal to [`Hand::lift`] is
+/// reserved above the cards so that selected cards can be raised without the rest of the hand
+/// moving.
+///
+/// `Hand` implements [`StatefulWidget`] with [`HandState`] to track which cards are selected, and
+/// [`Widget`] for rendering without any selection.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{Card, Hand, HandState, Rank, Suit};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let hand = Hand::new([
+///     Card::new(Rank::Ace, Suit::Spades),
+///     Card::new(Rank::King, Suit::Hearts),
+///     Card::new(Rank::Queen, Suit::Diamonds),
+/// ]);
+/// let mut state = HandState::default();
+/// state.toggle(1);
+/// frame.render_stateful_widget(&hand, frame.area(), &mut state);
+/// # }
+/// ```
+///
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+/// [`Widget`]: ratatui_core::widgets::Widget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    /// The cards in the hand, from left to right.
+    pub cards: Vec<Card>,
+    /// The number of rows selected cards are raised by. Defaults to 1.
+    pub lift: u16,
+    /// The number of columns between the left edges of two adjacent cards. Defaults to 4. See
+    /// [`Hand::spacing`].
+    pub spacing: u16,
+    /// The indices of the cards drawn with [`Hand::highlight_style`].
+    pub highlighted: HashSet<usize>,
+    /// The style applied to the border of highlighted cards. Defaults to a green foreground.
+    pub highlight_style: Style,
+    /// The style of the shadow cast by raised cards when they pop out of the hand, or `None` to
+    /// draw raised cards in place. Defaults to `None`. See [`Hand::pop_out`].
+    pub pop_out: Option<Style>,
+    /// The number of columns the hand is scrolled to the left by. Defaults to 0.
+    pub scroll: u16,
+    /// The width the overlap between cards is chosen to fit the hand within, or `None` for the
+    /// default overlap. Defaults to `None`. See [`Hand::fit_to_width`].
+    pub fit_width: Option<u16>,
+    /// The text rendered in place of the cards when the hand is empty, or `None` to render
+    /// nothing. Defaults to `None`. See [`Hand::empty_placeholder`].
+    pub empty_placeholder: Option<Text<'static>>,
+    /// The number of rows the cards at the ends of the hand drop below the middle cards. Defaults
+    /// to 0. See [`Hand::arc`].
+    pub arc: u16,
+    /// The angle in degrees that the cards at the ends of the hand are tilted by. Defaults to 0.
+    /// See [`Hand::fan_angle`].
+    pub fan_angle: u8,
+}
+
+/// The selection state of a [`Hand`].
+///
+/// A hand supports two kinds of selection which can be used together:
+///
+/// - a single selected card, like the cursor in a list, set with [`HandState::select`].
+/// - a set of selected cards, toggled individually with [`HandState::toggle`], for games that need
+///   to pick several cards at once (e.g. melds in rummy).
+///
+/// Cards in either selection are rendered raised above the rest of the hand.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HandState {
+    selected: Option<usize>,
+    selection: HashSet<usize>,
+}
+
+impl Hand {
+    /// Creates a new hand from the given cards.
+    pub fn new<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        Self {
+            cards: cards.into_iter().collect(),
+            lift: DEFAULT_LIFT,
+            spacing: DEFAULT_SPACING,
+            highlighted: HashSet::new(),
+            highlight_style: Style::new().green(),
+            pop_out: None,
+            scroll: 0,
+            fit_width: None,
+            empty_placeholder: None,
+            arc: 0,
+            fan_angle: 0,
+        }
+    }
+
+    /// Creates a hand of `count` face down cards of the given size, e.g. for an opponent's hidden
+    /// hand.
+    ///
+    /// Only the backs are shown, so the cards are all the Ace of Spades as a placeholder. The hand
+    /// is fanned like any other, so the number of cards is visible at a glance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardSize, Hand};
+    ///
+    /// # fn draw(frame: &mut ratatui::Frame) {
+    /// let opponent = Hand::all_face_down(7, CardSize::Small);
+    /// frame.render_widget(&opponent, frame.area());
+    /// # }
+    /// ```
+    pub fn all_face_down(count: usize, size: CardSize) -> Self {
+        let card = Card::new(Rank::Ace, Suit::Spades)
+            .size(size)
+            .facing(Facing::Down);
+        Self::new(iter::repeat_n(card, count))
+    }
+
+    /// Sets the number of rows selected cards are raised above the rest of the hand.
+    ///
+    /// The hand always reserves this many rows of headroom above the unselected cards, so the
+    /// layout does not jump when the selection changes. When the area is too short for the cards
+    /// plus the full lift, the lift is reduced to fit so cards never escape the area.
+    ///
+    /// Lifting only moves cards vertically; the horizontal overlap between cards is unchanged. The
+    /// bottom `rows` rows of a raised card's visible strip show the card underneath it instead,
+    /// and a lift of the card height or more separates the card from the hand entirely.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades)]).lift(3);
+    /// ```
+    #[must_use]
+    pub fn lift(mut self, rows: u16) -> Self {
+        self.lift = rows;
+        self
+    }
+
+    /// Sets the number of columns between the left edges of two adjacent cards, which is how much
+    /// of every card but the last is visible.
+    ///
+    /// The default of 4 shows the corner index of normal and small cards. A spacing of the card
+    /// width or more lays the cards out side by side without overlapping, and a spacing of 0
+    /// stacks them in a pile. This is the widest the cards are spread: a hand wider than the area
+    /// it is rendered in (or than the width given to [`Hand::fit_to_width`]) is tightened so that
+    /// it still fits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, Hand, Rank, Suit};
+    ///
+    /// let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+    /// let hand = Hand::new([card; 3]).spacing(2);
+    /// assert_eq!(hand.width(), 12);
+    /// ```
+    #[must_use]
+    pub const fn spacing(mut self, columns: u16) -> Self {
+        self.spacing = columns;
+        self
+    }
+
+    /// Sets the indices of the cards to highlight, e.g. the cards forming the best poker hand.
+    ///
+    /// Highlighted cards are drawn [highlighted](Card::highlighted) with [`Hand::highlight_style`]
+    /// in place of their own [`Card::highlight_style`], while the other cards render as they are,
+    /// so a card that is already highlighted itself keeps its own highlight. Highlighting is
+    /// independent of the selection in [`HandState`]. Indices that are out of range for this hand
+    /// are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::new([
+    ///     Card::new(Rank::Nine, Suit::Spades),
+    ///     Card::new(Rank::Nine, Suit::Hearts),
+    ///     Card::new(Rank::Two, Suit::Clubs),
+    /// ])
+    /// .highlight([0, 1]);
+    /// ```
+    #[must_use]
+    pub fn highlight<I: IntoIterator<Item = usize>>(mut self, indices: I) -> Self {
+        self.highlighted = indices.into_iter().collect();
+        self
+    }
+
+    /// Sets the style applied to the border of the cards highlighted with [`Hand::highlight`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades)])
+    ///     .highlight([0])
+    ///     .highlight_style(Style::new().yellow().bold());
+    /// ```
+    #[must_use]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Makes raised cards pop out of the hand, drawn on top of the other cards and casting a
+    /// shadow in the given style.
+    ///
+    /// By default a raised card stays in its place in the fan, so the cards to its right still
+    /// cover most of it. Popped out cards are drawn after every other card, so they are shown in
+    /// full, with a shadow one cell below and to the right of the card that makes it look lifted
+    /// off the table. The shadow only changes the style of the cells it falls on, so the cards
+    /// beneath it stay readable. Combine this with [`Hand::lift`] to control the height.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::new([
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::King, Suit::Hearts),
+    /// ])
+    /// .lift(2)
+    /// .pop_out(Style::new().bg(Color::DarkGray));
+    /// ```
+    #[must_use]
+    pub fn pop_out<S: Into<Style>>(mut self, shadow: S) -> Self {
+        self.pop_out = Some(shadow.into());
+        self
+    }
+
+    /// Scrolls the hand to the left by the given number of columns, for hands wider than the area
+    /// they are rendered in.
+    ///
+    /// Cards scrolled past the left edge of the area are hidden, and a card straddling the edge
+    /// shows only its right part. Compare the offset with [`Hand::width`] to keep the last card in
+    /// view. [`Hand::card_at`] takes the offset into account.
+    ///
+    /// A hand wider than the area is only tightened to fit the area widened by the scrolled
+    /// columns, so scrolling shows the cards at their spacing rather than drawn closer together.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Position, Rect};
+    /// use tui_cards::{Card, Hand, HandState, Rank, Suit};
+    ///
+    /// let hand = Hand::new([
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::King, Suit::Hearts),
+    /// ])
+    /// .scroll(6);
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let state = HandState::default();
+    /// assert_eq!(hand.card_at(area, &state, Position::new(0, 5)), Some(1));
+    /// ```
+    #[must_use]
+    pub fn scroll(mut self, offset_cols: u16) -> Self {
+        self.scroll = offset_cols;
+        self
+    }
+
+    /// Tightens the overlap between cards so that the whole hand fits within `width` columns.
+    ///
+    /// The cards are brought closer together until the last card ends at the given width, but are
+    /// never spread further apart than [`Hand::spacing`], so a hand narrower than `width` keeps its
+    /// spacing. When even the tightest fan, with a single column of each card visible, is wider
+    /// than `width`, the hand is clipped on the right, and [`Hand::scroll`] can be used to show the
+    /// rest.
+    ///
+    /// A hand is always tightened to fit the area it is rendered in, so this is only needed to fit
+    /// it within a narrower width, or for [`Hand::width`] to report the fitted width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let cards = [Card::new(Rank::Ace, Suit::Spades); 10];
+    /// let hand = Hand::new(cards).fit_to_width(40);
+    /// assert!(hand.width() <= 40);
+    /// ```
+    #[must_use]
+    pub fn fit_to_width(mut self, width: u16) -> Self {
+        self.fit_width = Some(width);
+        self
+    }
+
+    /// Sets the text rendered in place of the cards when the hand is empty, e.g. "no cards".
+    ///
+    /// The text is drawn from the top left of the area, clipped to it. With `None`, the default,
+    /// an empty hand renders nothing. The placeholder is ignored while the hand has any cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::Text;
+    /// use tui_cards::Hand;
+    ///
+    /// let hand = Hand::default().empty_placeholder(Some(Text::raw("no cards")));
+    /// ```
+    #[must_use]
+    pub fn empty_placeholder(mut self, placeholder: Option<Text<'static>>) -> Self {
+        self.empty_placeholder = placeholder;
+        self
+    }
+
+    /// Curves the hand into a gentle arc, as if it were held in a hand, with the cards at the ends
+    /// dropping `height` rows below the middle cards.
+    ///
+    /// Each card drops by an amount that grows with the square of its distance from the middle of
+    /// the hand, tracing a parabola. The rows below the middle cards are reserved like the
+    /// headroom of [`Hand::lift`], and when the area is too short for the cards, the lift, and the
+    /// full arc, the arc is flattened to fit. Raised cards are lifted from their place on the arc.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades); 7]).arc(2);
+    /// ```
+    #[must_use]
+    pub const fn arc(mut self, height: u16) -> Self {
+        self.arc = height;
+        self
+    }
+
+    /// Tilts the cards like a fan held in a hand, with the cards at the ends leaning outwards by up
+    /// to `degrees` and the cards between them by proportionally less.
+    ///
+    /// Terminals cannot rotate text, so the tilt is approximated by shearing each card: every row
+    /// is shifted sideways by a whole number of columns that grows towards the top of the card,
+    /// keeping the bottom row in place. The border and art stay connected for small angles, but
+    /// large angles break them into visible steps and make the cards hard to read, so angles of
+    /// 5 to 15 degrees work best, and angles past 45 degrees are treated as 45. Sheared rows that
+    /// lean past the edge of the area are clipped. [`Hand::card_at`] follows the shear, but the
+    /// shadow of [popped out](Hand::pop_out) cards does not. Combine this with [`Hand::arc`] for
+    /// the look of a held hand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades); 5])
+    ///     .arc(1)
+    ///     .fan_angle(10);
+    /// ```
+    #[must_use]
+    pub const fn fan_angle(mut self, degrees: u8) -> Self {
+        self.fan_angle = degrees;
+        self
+    }
+
+    /// Groups the cards by suit, with the suits in the given order.
+    ///
+    /// The cards of each suit keep their order within the group, so a hand that is already sorted
+    /// by rank stays sorted. Highlighted cards stay highlighted at their new positions, but a
+    /// [`HandState`] refers to positions rather than cards, so clear or update it after grouping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, Rank, Suit, SuitOrder};
+    ///
+    /// let hand = Hand::new([
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Two, Suit::Clubs),
+    ///     Card::new(Rank::King, Suit::Spades),
+    /// ])
+    /// .grouped_by_suit(SuitOrder::Bridge);
+    /// assert_eq!(hand.cards[0].suit, Suit::Clubs);
+    /// ```
+    #[must_use]
+    pub fn grouped_by_suit(mut self, order: SuitOrder) -> Self {
+        let mut indices: Vec<usize> = (0..self.cards.len()).collect();
+        indices.sort_by_key(|&index| order.position(self.cards[index].suit));
+        self.cards = indices.iter().map(|&index| self.cards[index]).collect();
+        self.highlighted = indices
+            .iter()
+            .enumerate()
+            .filter(|(_, old)| self.highlighted.contains(old))
+            .map(|(new, _)| new)
+            .collect();
+        self
+    }
+
+    /// Returns the number of columns the hand occupies when it is not clipped or scrolled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::new([
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::King, Suit::Hearts),
+    /// ]);
+    /// assert_eq!(hand.width(), 18);
+    /// ```
+    pub fn width(&self) -> u16 {
+        self.width_at(self.card_spacing())
+    }
+
+    /// Returns the number of columns the hand occupies with the given spacing between cards.
+    fn width_at(&self, spacing: u16) -> u16 {
+        (0..)
+            .zip(&self.cards)
+            .map(|(index, card)| {
+                u16::saturating_mul(index, spacing).saturating_add(card.size.dimensions().width)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Removes and returns the card at `index`, or `None` if the index is out of range.
+    ///
+    /// The state is updated so both selections keep referring to the same cards. If the single
+    /// selected card is removed, the selection moves to the card that took its place (or the new
+    /// last card), and becomes `None` once the hand is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, HandState, Rank, Suit};
+    ///
+    /// let mut hand = Hand::new([
+    ///     Card::new(Rank::Two, Suit::Clubs),
+    ///     Card::new(Rank::Three, Suit::Clubs),
+    /// ]);
+    /// let mut state = HandState::default();
+    /// state.select(Some(1));
+    /// assert_eq!(
+    ///     hand.remove(0, &mut state),
+    ///     Some(Card::new(Rank::Two, Suit::Clubs))
+    /// );
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn remove(&mut self, index: usize, state: &mut HandState) -> Option<Card> {
+        if index >= self.cards.len() {
+            return None;
+        }
+        let card = self.cards.remove(index);
+        state.removed(index, self.cards.len());
+        Some(card)
+    }
+
+    /// Removes and returns the cards in the multiple selection of the given state, in hand order.
+    ///
+    /// The multiple selection is cleared. The single selected card keeps pointing at the same card
+    /// if it was not removed, and otherwise moves as described in [`Hand::remove`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, HandState, Rank, Suit};
+    ///
+    /// let mut hand = Hand::new([
+    ///     Card::new(Rank::Two, Suit::Clubs),
+    ///     Card::new(Rank::Three, Suit::Clubs),
+    ///     Card::new(Rank::Four, Suit::Clubs),
+    /// ]);
+    /// let mut state = HandState::default();
+    /// state.toggle(0);
+    /// state.toggle(2);
+    /// let discarded = hand.remove_selected(&mut state);
+    /// assert_eq!(discarded.len(), 2);
+    /// assert_eq!(hand.cards, [Card::new(Rank::Three, Suit::Clubs)]);
+    /// ```
+    pub fn remove_selected(&mut self, state: &mut HandState) -> Vec<Card> {
+        let mut removed = Vec::new();
+        for index in (0..self.cards.len()).rev() {
+            if state.is_toggled(index) {
+                removed.extend(self.remove(index, state));
+            }
+        }
+        removed.reverse();
+        // toggled indices past the end of the hand name no card, so they are dropped too
+        state.clear_selection();
+        removed
+    }
+
+    /// Inserts a card at `index`, shifting the cards after it to the right.
+    ///
+    /// An index past the end of the hand appends the card. The state is updated so both
+    /// selections keep referring to the same cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, HandState, Rank, Suit};
+    ///
+    /// let mut hand = Hand::new([Card::new(Rank::Two, Suit::Clubs)]);
+    /// let mut state = HandState::default();
+    /// state.select(Some(0));
+    /// hand.insert(0, Card::new(Rank::Ace, Suit::Clubs), &mut state);
+    /// assert_eq!(state.selected(), Some(1));
+    /// ```
+    pub fn insert(&mut self, index: usize, card: Card, state: &mut HandState) {
+        let index = index.min(self.cards.len());
+        self.cards.insert(index, card);
+        state.inserted(index);
+    }
+
+    /// Returns the cards in the multiple selection of the given state, in hand order.
+    ///
+    /// Indices in the selection that are out of range for this hand are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, Hand, HandState, Rank, Suit};
+    ///
+    /// let hand = Hand::new([
+    ///     Card::new(Rank::Two, Suit::Clubs),
+    ///     Card::new(Rank::Three, Suit::Clubs),
+    ///     Card::new(Rank::Four, Suit::Clubs),
+    /// ]);
+    /// let mut state = HandState::default();
+    /// state.toggle(2);
+    /// state.toggle(0);
+    /// assert_eq!(
+    ///     hand.selected_cards(&state),
+    ///     vec![hand.cards[0], hand.cards[2]]
+    /// );
+    /// ```
+    pub fn selected_cards(&self, state: &HandState) -> Vec<Card> {
+        self.cards
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| state.is_toggled(*index))
+            .map(|(_, card)| *card)
+            .collect()
+    }
+
+    /// Returns the index of the card drawn at the given position when the hand is rendered in
+    /// `area` with the given state, or `None` if no card is drawn there.
+    ///
+    /// Where cards overlap, the card drawn on top wins, which is the one further right, or a raised
+    /// card when they [pop out](Hand::pop_out). This is meant for mapping mouse clicks to cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Position, Rect};
+    /// use tui_cards::{Card, Hand, HandState, Rank, Suit};
+    ///
+    /// let hand = Hand::new([
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::King, Suit::Hearts),
+    /// ]);
+    /// let area = Rect::new(0, 0, 40, 10);
+    /// let state = HandState::default();
+    /// assert_eq!(hand.card_at(area, &state, Position::new(1, 5)), Some(0));
+    /// assert_eq!(hand.card_at(area, &state, Position::new(5, 5)), Some(1));
+    /// assert_eq!(hand.card_at(area, &state, Position::new(30, 5)), None);
+    /// ```
+    pub fn card_at(&self, area: Rect, state: &HandState, position: Position) -> Option<usize> {
+        self.layout(area, state)
+            .into_iter()
+            .rev()
+            .find(|placement| {
+                let shear = self.shear(placement.index);
+                if shear == 0.0 {
+                    return placement.bounds.intersection(area).contains(position);
+                }
+                let height = placement.bounds.height;
+                let (Some(row), true) = (
+                    position.y.checked_sub(placement.bounds.y),
+                    area.contains(position),
+                ) else {
+                    return false;
+                };
+                let left = i32::from(placement.bounds.x) - i32::from(placement.hidden)
+                    + row_shift(shear, row, height);
+                let column = i32::from(position.x) - left;
+                let width = i32::from(placement.bounds.width + placement.hidden);
+                row < height && (0..width).contains(&column)
+            })
+            .map(|placement| placement.index)
+    }
+
+    /// Returns the number of columns each row of the card at `index` shifts relative to the row
+    /// below it when the hand is fanned, negative for cards leaning left.
+    fn shear(&self, index: usize) -> f64 {
+        let Some(last) = self.cards.len().checked_sub(1).filter(|last| *last > 0) else {
+            return 0.0;
+        };
+        if self.fan_angle == 0 {
+            return 0.0;
+        }
+        // from -1 for the first card to 1 for the last
+        let position = (2 * index) as f64 / last as f64 - 1.0;
+        let degrees = f64::from(self.fan_angle.min(MAX_FAN_ANGLE)) * position;
+        degrees.to_radians().tan() * CELL_ASPECT
+    }
+
+    /// Returns the number of columns between the left edges of two adjacent cards, tightened to
+    /// the width given to [`Hand::fit_to_width`].
+    fn card_spacing(&self) -> u16 {
+        match self.fit_width {
+            Some(width) => self.spacing.min(self.fitted_spacing(width)),
+            None => self.spacing,
+        }
+    }
+
+    /// Returns the number of columns between the left edges of two adjacent cards when the hand
+    /// is rendered `width` columns wide, tightened if the hand would be wider than that.
+    fn spacing_within(&self, width: u16) -> u16 {
+        let spacing = self.card_spacing();
+        if self.width_at(spacing) > width {
+            spacing.min(self.fitted_spacing(width))
+        } else {
+            spacing
+        }
+    }
+
+    /// Returns the widest spacing at which the hand fits within `width` columns, but at least 1 so
+    /// that a column of every card stays visible.
+    fn fitted_spacing(&self, width: u16) -> u16 {
+        let gaps = self.cards.len().checked_sub(1).map(u16::try_from);
+        let (Some(last), Some(Ok(gaps @ 1..))) = (self.cards.last(), gaps) else {
+            // a single card has no spacing to tighten
+            return u16::MAX;
+        };
+        (width.saturating_sub(last.size.dimensions().width) / gaps).max(1)
+    }
+
+    /// Returns the placement of each card that is at least partly within the area, in the order
+    /// they are drawn.
+    fn layout(&self, area: Rect, state: &HandState) -> Vec<Placement> {
+        let card_height = self
+            .cards
+            .iter()
+            .map(|card| card.size.dimensions().height)
+            .max()
+            .unwrap_or_default();
+        let headroom = self.lift.min(area.height.saturating_sub(card_height));
+        let arc = self
+            .arc
+            .min(area.height.saturating_sub(card_height + headroom));
+        let spacing = self.spacing_within(area.width.saturating_add(self.scroll));
+        let mut layout = Vec::with_capacity(self.cards.len());
+        for (index, card) in self.cards.iter().enumerate() {
+            let size = card.size.dimensions();
+            // the column of the card's left edge relative to the area, negative when scrolled past
+            let offset = i64::try_from(index).unwrap_or(i64::MAX) * i64::from(spacing)
+                - i64::from(self.scroll);
+            if offset >= i64::from(area.width) {
+                break;
+            }
+            let hidden = u16::try_from((-offset).max(0)).unwrap_or(u16::MAX);
+            if hidden >= size.width {
+                continue;
+            }
+            let x = area.x + u16::try_from(offset).unwrap_or_default();
+            let y = area.y + arc_drop(index, self.cards.len(), arc);
+            let y = if state.is_raised(index) {
+                y
+            } else {
+                y + headroom
+            };
+            layout.push(Placement {
+                index,
+                bounds: Rect::new(x, y, size.width - hidden, size.height),
+                hidden,
+            });
+        }
+        if self.pop_out.is_some() {
+            // the sort is stable, so raised cards keep their order among themselves
+            layout.sort_by_key(|placement| state.is_raised(placement.index));
+        }
+        layout
+    }
+}
+
+/// Returns the number of rows the card at `index` drops below the middle of a hand of `len` cards
+/// curved into an arc of the given height, rounded to the nearest row.
+fn arc_drop(index: usize, len: usize, height: u16) -> u16 {
+    let Some(last) = len.checked_sub(1).filter(|last| *last > 0) else {
+        return 0;
+    };
+    // distances are doubled so that the middle of a hand with an even number of cards, which lies
+    // between two cards, is a whole number
+    let distance = (2 * index).abs_diff(last) as u64;
+    let (distance, last) = (distance * distance, (last * last) as u64);
+    let drop = (u64::from(height) * distance + last / 2) / last;
+    u16::try_from(drop).unwrap_or(height)
+}
+
+/// Where a card of a [`Hand`] is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Placement {
+    /// The index of the card in the hand.
+    index: usize,
+    /// The bounds of the visible part of the card, which are only clipped on the left by scrolling
+    /// and may extend past the rest of the area.
+    bounds: Rect,
+    /// The number of columns of the card scrolled past the left edge of the area.
+    hidden: u16,
+}
+
+impl HandState {
+    /// Returns the index of the single selected card, if any.
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Sets the single selected card.
+    pub const fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Adds the card at `index` to the multiple selection, or removes it if it is already there.
+    pub fn toggle(&mut self, index: usize) {
+        if !self.selection.remove(&index) {
+            self.selection.insert(index);
+        }
+    }
+
+    /// Returns true if the card at `index` is part of the multiple selection.
+    pub fn is_toggled(&self, index: usize) -> bool {
+        self.selection.contains(&index)
+    }
+
+    /// Returns the indices of the multiple selection.
+    pub const fn selection(&self) -> &HashSet<usize> {
+        &self.selection
+    }
+
+    /// Removes every card from the multiple selection.
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+    }
+
+    /// Updates the selections after the card at `index` was removed, leaving `len` cards.
+    fn removed(&mut self, index: usize, len: usize) {
+        self.selected = match self.selected {
+            _ if len == 0 => None,
+            Some(selected) if selected > index => Some(selected - 1),
+            Some(selected) => Some(selected.min(len - 1)),
+            None => None,
+        };
+        self.selection = self
+            .selection
+            .iter()
+            .filter(|&&selected| selected != index)
+            .map(|&selected| {
+                if selected > index {
+                    selected - 1
+                } else {
+                    selected
+                }
+            })
+            .collect();
+    }
+
+    /// Updates the selections after a card was inserted at `index`.
+    fn inserted(&mut self, index: usize) {
+        self.selected = self.selected.map(|selected| {
+            if selected >= index {
+                selected + 1
+            } else {
+                selected
+            }
+        });
+        self.selection = self
+            .selection
+            .iter()
+            .map(|&selected| {
+                if selected >= index {
+                    selected + 1
+                } else {
+                    selected
+                }
+            })
+            .collect();
+    }
+
+    /// Returns true if the card at `index` should be rendered raised.
+    fn is_raised(&self, index: usize) -> bool {
+        self.selected == Some(index) || self.is_toggled(index)
+    }
+}
+
+impl Default for Hand {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl Widget for &Hand {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        StatefulWidget::render(self, area, buf, &mut HandState::default());
+    }
+}
+
+impl StatefulWidget for &Hand {
+    type State = HandState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if self.cards.is_empty() {
+            if let Some(placeholder) = &self.empty_placeholder {
+                placeholder.render(area, buf);
+            }
+            return;
+        }
+        for placement in self.layout(area, state) {
+            let Placement {
+                index,
+                bounds,
+                hidden,
+            } = placement;
+            if let Some(shadow) = self.pop_out.filter(|_| state.is_raised(index)) {
+                cast_shadow(placement, area, buf, shadow);
+            }
+            let mut card = self.cards[index];
+            if self.highlighted.contains(&index) {
+                card = card.highlighted(true).highlight_style(self.highlight_style);
+            }
+            let shear = self.shear(index);
+            if shear != 0.0 {
+                render_sheared(&card, placement, area, buf, shear);
+            } else if hidden == 0 {
+                card.render(bounds.intersection(area), buf);
+            } else {
+                render_scrolled(&card, placement, area, buf);
+            }
+        }
+    }
+}
+
+/// Renders a card that is partly scrolled past the left edge of the area, showing only its right
+/// part.
+///
+/// The whole card is rendered into a scratch buffer, which the visible columns are copied from.
+fn render_scrolled(card: &Card, placement: Placement, area: Rect, buf: &mut Buffer) {
+    let Placement { bounds, hidden, .. } = placement;
+    let size = card.size.dimensions();
+    let mut scratch = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+    card.render(scratch.area, &mut scratch);
+    for position in bounds.intersection(area).positions() {
+        let x = position.x - bounds.x + hidden;
+        let y = position.y - bounds.y;
+        let mut cell = scratch[(x, y)].clone();
+        // the left half of a wide symbol was scrolled away, so its right half is blanked in the
+        // style of the symbol rather than left reset
+        if position.x == bounds.x && scratch[(x - 1, y)].symbol().width() > 1 {
+            cell = scratch[(x - 1, y)].clone();
+            cell.set_symbol(" ");
+        }
+        buf[position] = cell;
+    }
+}
+
+/// Renders a card with each row shifted sideways by the shear times its height above the bottom
+/// row, approximating a tilted card.
+///
+/// The whole card is rendered into a scratch buffer, whose rows are copied to their shifted
+/// positions within the area.
+fn render_sheared(card: &Card, placement: Placement, area: Rect, buf: &mut Buffer, shear: f64) {
+    let Placement { bounds, hidden, .. } = placement;
+    let size = card.size.dimensions();
+    let mut scratch = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+    card.render(scratch.area, &mut scratch);
+    let left = i32::from(bounds.x) - i32::from(hidden);
+    for (x, y) in (0..size.width).cartesian_product(0..size.height) {
+        let column = left + row_shift(shear, y, size.height) + i32::from(x);
+        let Ok(column) = u16::try_from(column) else {
+            continue;
+        };
+        let position = Position::new(column, bounds.y.saturating_add(y));
+        if !area.contains(position) {
+            continue;
+        }
+        let mut cell = scratch[(x, y)].clone();
+        // the left half of a wide symbol was clipped, so its right half is blanked in the style of
+        // the symbol rather than left reset
+        if position.x == area.x && x > 0 && scratch[(x - 1, y)].symbol().width() > 1 {
+            cell = scratch[(x - 1, y)].clone();
+            cell.set_symbol(" ");
+        }
+        buf[position] = cell;
+    }
+}
+
+/// Returns the number of columns the given row of a sheared card of the given height is shifted
+/// by, rounded to a whole column.
+fn row_shift(shear: f64, row: u16, height: u16) -> i32 {
+    (shear * f64::from(height.saturating_sub(1).saturating_sub(row))).round() as i32
+}
+
+/// Applies the style to the cells one column right of and one row below the card that lie within
+/// `area`, as if the card were lifted off the table.
+///
+/// The whole offset area is styled, so the card must be rendered afterwards to cover its part.
+fn cast_shadow(placement: Placement, area: Rect, buf: &mut Buffer, style: Style) {
+    let Placement { bounds, hidden, .. } = placement;
+    // the shadow of a card scrolled past the edge is also scrolled, so it starts at the edge
+    let shadow = if hidden == 0 {
+        Rect {
+            x: bounds.x.saturating_add(1),
+            ..bounds
+        }
+    } else {
+        Rect {
+            width: bounds.width.saturating_add(1),
+            ..bounds
+        }
+    };
+    let shadow = Rect {
+        y: shadow.y.saturating_add(1),
+        ..shadow
+    };
+    buf.set_style(shadow.intersection(area), style);
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Color;
+
+    use super::*;
+
+    fn hand() -> Hand {
+        Hand::new([
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Three, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ])
+    }
+
+    #[test]
+    fn fan_angle_shears_outer_cards() {
+        let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small); 3])
+            .lift(0)
+            .fan_angle(20);
+        assert!(hand.shear(0) < 0.0);
+        assert_eq!(hand.shear(1), 0.0);
+        assert!(hand.shear(2) > 0.0);
+        // tan(20°) * 2 is about 0.73 columns per row
+        let shifts: Vec<_> = (0..5).map(|row| row_shift(hand.shear(2), row, 5)).collect();
+        assert_eq!(shifts, [3, 2, 1, 1, 0]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 5));
+        Widget::render(&hand, buf.area, &mut buf);
+        // the last card starts at column 8 on its bottom row and leans right above it
+        assert_eq!(buf[(8, 4)].symbol(), "╰");
+        assert_eq!(buf[(11, 0)].symbol(), "╭");
+        assert_eq!(buf[(18, 0)].symbol(), "╮");
+        // the first card leans left past the edge of the area and is clipped
+        assert_eq!(buf[(0, 4)].symbol(), "╰");
+        let state = HandState::default();
+        assert_eq!(
+            hand.card_at(buf.area, &state, Position::new(18, 0)),
+            Some(2)
+        );
+        assert_eq!(hand.card_at(buf.area, &state, Position::new(19, 4)), None);
+    }
+
+    #[test]
+    fn fan_angle_leaves_single_card_upright() {
+        let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+        let fanned = Hand::new([card]).fan_angle(30);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 6));
+        let mut expected = buf.clone();
+        Widget::render(&fanned, buf.area, &mut buf);
+        Widget::render(&Hand::new([card]), expected.area, &mut expected);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn arc_drop_traces_parabola() {
+        let drops: Vec<_> = (0..5).map(|index| arc_drop(index, 5, 4)).collect();
+        assert_eq!(drops, [4, 1, 0, 1, 4]);
+        let drops: Vec<_> = (0..4).map(|index| arc_drop(index, 4, 3)).collect();
+        assert_eq!(drops, [3, 0, 0, 3]);
+        assert_eq!(arc_drop(0, 1, 5), 0);
+    }
+
+    #[test]
+    fn arc_offsets_cards_and_raises_from_the_arc() {
+        let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small); 3])
+            .lift(1)
+            .arc(2);
+        let mut state = HandState::default();
+        state.select(Some(2));
+        let area = Rect::new(0, 0, 20, 8);
+        let tops: Vec<_> = hand
+            .layout(area, &state)
+            .iter()
+            .map(|placement| placement.bounds.y)
+            .collect();
+        assert_eq!(tops, [3, 1, 2]);
+        // a short area flattens the arc
+        let tops: Vec<_> = hand
+            .layout(Rect::new(0, 0, 20, 7), &state)
+            .iter()
+            .map(|placement| placement.bounds.y)
+            .collect();
+        assert_eq!(tops, [2, 1, 1]);
+    }
+
+    #[test]
+    fn all_face_down_renders_backs() {
+        let hand = Hand::all_face_down(3, CardSize::Small);
+        assert_eq!(hand.cards.len(), 3);
+        assert!(hand.cards.iter().all(|card| card.facing == Facing::Down));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 6));
+        Widget::render(&hand, buf.area, &mut buf);
+        let backs = Hand::new(
+            [Card::new(Rank::Two, Suit::Hearts)
+                .size(CardSize::Small)
+                .facing(Facing::Down); 3],
+        );
+        let mut expected = Buffer::empty(buf.area);
+        Widget::render(&backs, expected.area, &mut expected);
+        assert_eq!(buf, expected);
+        assert_eq!(buf[(15, 3)].symbol(), "│");
+    }
+
+    #[test]
+    fn grouped_by_suit_keeps_rank_order_and_highlights() {
+        let hand = Hand::new([
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Five, Suit::Hearts),
+        ])
+        .highlight([1, 2, 9]);
+        let bridge = hand.clone().grouped_by_suit(SuitOrder::Bridge);
+        let ranks: Vec<_> = bridge.cards.iter().map(|card| card.rank).collect();
+        assert_eq!(ranks, [Rank::Three, Rank::Five, Rank::Two, Rank::Four]);
+        assert_eq!(bridge.highlighted, HashSet::from([0, 3]));
+        let declaration = hand.grouped_by_suit(SuitOrder::Declaration);
+        let ranks: Vec<_> = declaration.cards.iter().map(|card| card.rank).collect();
+        assert_eq!(ranks, [Rank::Two, Rank::Four, Rank::Five, Rank::Three]);
+        assert_eq!(declaration.highlighted, HashSet::from([1, 3]));
+    }
+
+    #[test]
+    fn toggle_adds_and_removes() {
+        let mut state = HandState::default();
+        state.toggle(1);
+        state.toggle(2);
+        assert!(state.is_toggled(1));
+        assert!(state.is_toggled(2));
+        state.toggle(1);
+        assert!(!state.is_toggled(1));
+        assert_eq!(state.selection(), &HashSet::from([2]));
+        state.clear_selection();
+        assert!(state.selection().is_empty());
+    }
+
+    #[test]
+    fn selected_cards_are_in_hand_order() {
+        let hand = hand();
+        let mut state = HandState::default();
+        state.toggle(2);
+        state.toggle(0);
+        state.toggle(7);
+        assert_eq!(
+            hand.selected_cards(&state),
+            vec![hand.cards[0], hand.cards[2]]
+        );
+    }
+
+    #[test]
+    fn remove_adjusts_selection() {
+        let mut hand = hand();
+        let mut state = HandState::default();
+        state.select(Some(2));
+        state.toggle(0);
+        state.toggle(2);
+        assert_eq!(
+            hand.remove(1, &mut state),
+            Some(Card::new(Rank::Three, Suit::Hearts))
+        );
+        assert_eq!(state.selected(), Some(1));
+        assert_eq!(state.selection(), &HashSet::from([0, 1]));
+        assert_eq!(hand.remove(5, &mut state), None);
+        assert_eq!(hand.cards.len(), 2);
+    }
+
+    #[test]
+    fn remove_selected_card_moves_selection() {
+        let mut hand = hand();
+        let mut state = HandState::default();
+        state.select(Some(2));
+        hand.remove(2, &mut state);
+        assert_eq!(state.selected(), Some(1));
+        state.select(Some(0));
+        hand.remove(0, &mut state);
+        assert_eq!(state.selected(), Some(0));
+        hand.remove(0, &mut state);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn remove_selected_returns_toggled_cards() {
+        let mut hand = hand();
+        let mut state = HandState::default();
+        state.select(Some(1));
+        state.toggle(0);
+        state.toggle(2);
+        let removed = hand.remove_selected(&mut state);
+        assert_eq!(
+            removed,
+            [
+                Card::new(Rank::Two, Suit::Spades),
+                Card::new(Rank::Four, Suit::Diamonds)
+            ]
+        );
+        assert_eq!(hand.cards, [Card::new(Rank::Three, Suit::Hearts)]);
+        assert!(state.selection().is_empty());
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn remove_selected_clears_out_of_range_toggles() {
+        let mut hand = hand();
+        let mut state = HandState::default();
+        state.toggle(1);
+        state.toggle(10);
+        let removed = hand.remove_selected(&mut state);
+        assert_eq!(removed, [Card::new(Rank::Three, Suit::Hearts)]);
+        assert!(state.selection().is_empty());
+        // the stale index must not select a card added to the hand later
+        for _ in 0..10 {
+            hand.cards.push(Card::new(Rank::Ace, Suit::Clubs));
+        }
+        assert!(!state.is_toggled(10));
+    }
+
+    #[test]
+    fn insert_adjusts_selection() {
+        let mut hand = hand();
+        let mut state = HandState::default();
+        state.select(Some(1));
+        state.toggle(0);
+        state.toggle(2);
+        let ace = Card::new(Rank::Ace, Suit::Clubs);
+        hand.insert(1, ace, &mut state);
+        assert_eq!(hand.cards[1], ace);
+        assert_eq!(state.selected(), Some(2));
+        assert_eq!(state.selection(), &HashSet::from([0, 3]));
+        hand.insert(10, ace, &mut state);
+        assert_eq!(hand.cards.len(), 5);
+        assert_eq!(hand.cards[4], ace);
+    }
+
+    #[test]
+    fn renders_selected_cards_raised() {
+        let hand = hand();
+        let mut state = HandState::default();
+        state.select(Some(0));
+        state.toggle(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 10));
+        StatefulWidget::render(&hand, buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(0, 0)].symbol(), "╭");
+        assert_eq!(buf[(4, 0)].symbol(), "─");
+        assert_eq!(buf[(4, 1)].symbol(), "╭");
+        assert_eq!(buf[(8, 0)].symbol(), "╭");
+        // the index of every card stays visible
+        assert_eq!(buf[(2, 1)].symbol(), "2");
+        assert_eq!(buf[(6, 2)].symbol(), "3");
+        assert_eq!(buf[(10, 1)].symbol(), "4");
+    }
+
+    #[test]
+    fn lift_reserves_headroom() {
+        let hand = hand().lift(3);
+        let mut state = HandState::default();
+        state.select(Some(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 12));
+        StatefulWidget::render(&hand, buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(0, 2)].symbol(), " ");
+        assert_eq!(buf[(0, 3)].symbol(), "╭");
+        assert_eq!(buf[(4, 0)].symbol(), "╭");
+        assert_eq!(buf[(8, 3)].symbol(), "╭");
+    }
+
+    #[test]
+    fn lift_is_bounded_by_area() {
+        let hand = hand().lift(5);
+        let mut state = HandState::default();
+        state.select(Some(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 11));
+        StatefulWidget::render(&hand, buf.area, &mut buf, &mut state);
+        // only 2 rows of headroom are available
+        assert_eq!(buf[(0, 2)].symbol(), "╭");
+        assert_eq!(buf[(0, 10)].symbol(), "╰");
+        assert_eq!(buf[(4, 0)].symbol(), "╭");
+    }
+
+    #[test]
+    fn highlights_card_borders() {
+        let hand = hand().highlight([1, 5]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 10));
+        Widget::render(&hand, buf.area, &mut buf);
+        // the visible edge of the highlighted middle card
+        assert_eq!(buf[(4, 1)].fg, Color::Green);
+        assert_eq!(buf[(4, 5)].fg, Color::Green);
+        assert_eq!(buf[(5, 9)].fg, Color::Green);
+        // its index is not part of the border
+        assert_eq!(buf[(6, 2)].fg, Color::Red);
+        // the other cards render normally
+        assert_eq!(buf[(0, 5)].fg, Color::Black);
+        assert_eq!(buf[(21, 5)].fg, Color::Blue);
+    }
+
+    #[test]
+    fn highlight_draws_cards_highlighted_in_the_hand_style() {
+        let own = Style::new().yellow();
+        let mut hand = hand().spacing(14).highlight([0]);
+        hand.cards[1] = hand.cards[1].highlighted(true).highlight_style(own);
+        hand.cards[0] = hand.cards[0].highlight_style(own);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 42, 10));
+        Widget::render(&hand, buf.area, &mut buf);
+        let mut expected = Buffer::empty(buf.area);
+        let highlighted = hand.cards[0]
+            .highlighted(true)
+            .highlight_style(Color::Green);
+        highlighted.render(Rect::new(0, 1, 14, 9), &mut expected);
+        hand.cards[1].render(Rect::new(14, 1, 14, 9), &mut expected);
+        hand.cards[2].render(Rect::new(28, 1, 14, 9), &mut expected);
+        // the hand's highlight style replaces the card's own, and cards the hand does not
+        // highlight keep their own highlight
+        assert_eq!(buf, expected);
+        assert_eq!(buf[(0, 5)].fg, Color::Green);
+        assert_eq!(buf[(14, 5)].fg, Color::Yellow);
+    }
+
+    #[test]
+    fn card_at_boundary_columns() {
+        let hand = hand();
+        let area = Rect::new(0, 0, 22, 10);
+        let state = HandState::default();
+        let at = |x, y| hand.card_at(area, &state, Position::new(x, y));
+        assert_eq!(at(0, 5), Some(0));
+        assert_eq!(at(3, 5), Some(0));
+        assert_eq!(at(4, 5), Some(1));
+        assert_eq!(at(7, 5), Some(1));
+        assert_eq!(at(8, 5), Some(2));
+        assert_eq!(at(21, 5), Some(2));
+        assert_eq!(at(22, 5), None);
+        // the headroom above unselected cards is empty
+        assert_eq!(at(0, 0), None);
+        assert_eq!(at(0, 1), Some(0));
+    }
+
+    #[test]
+    fn card_at_raised_card() {
+        let hand = hand();
+        let area = Rect::new(0, 0, 22, 10);
+        let mut state = HandState::default();
+        state.select(Some(1));
+        let at = |x, y| hand.card_at(area, &state, Position::new(x, y));
+        assert_eq!(at(5, 0), Some(1));
+        // the raised card does not cover the bottom row of the card beneath it
+        assert_eq!(at(5, 9), Some(0));
+        assert_eq!(at(5, 8), Some(1));
+    }
+
+    #[test]
+    fn card_at_outside_area() {
+        let hand = hand();
+        let state = HandState::default();
+        let area = Rect::new(10, 5, 22, 10);
+        assert_eq!(hand.card_at(area, &state, Position::new(2, 8)), None);
+        assert_eq!(hand.card_at(area, &state, Position::new(10, 6)), Some(0));
+    }
+
+    #[test]
+    fn pop_out_draws_raised_cards_on_top_with_shadow() {
+        let hand = hand().pop_out(Style::new().bg(Color::DarkGray));
+        let mut state = HandState::default();
+        state.select(Some(0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 11));
+        StatefulWidget::render(&hand, buf.area, &mut buf, &mut state);
+        // the raised card covers the card to its right
+        assert_eq!(buf[(13, 0)].symbol(), "╮");
+        assert_eq!(buf[(13, 8)].symbol(), "╯");
+        // the shadow falls on the cards beneath, one cell right and below
+        assert_eq!(buf[(14, 1)].bg, Color::DarkGray);
+        assert_eq!(buf[(14, 1)].symbol(), "─");
+        assert_eq!(buf[(5, 9)].bg, Color::DarkGray);
+        assert_eq!(buf[(0, 9)].bg, Color::Reset);
+        assert_eq!(buf[(13, 8)].bg, Color::White);
+        let at = |x, y| hand.card_at(buf.area, &state, Position::new(x, y));
+        assert_eq!(at(10, 5), Some(0));
+        assert_eq!(at(14, 5), Some(2));
+    }
+
+    #[test]
+    fn pop_out_keeps_unraised_cards_in_place() {
+        let mut popped = Buffer::empty(Rect::new(0, 0, 22, 10));
+        let mut in_place = popped.clone();
+        Widget::render(
+            &hand().pop_out(Style::new().reversed()),
+            popped.area,
+            &mut popped,
+        );
+        Widget::render(&hand(), in_place.area, &mut in_place);
+        assert_eq!(popped, in_place);
+    }
+
+    #[test]
+    fn width_spans_the_fan() {
+        assert_eq!(hand().width(), 22);
+        assert_eq!(Hand::default().width(), 0);
+        let small = Hand::new([Card::new(Rank::Ace, Suit::Spades).size(crate::CardSize::Small)]);
+        assert_eq!(small.width(), 8);
+    }
+
+    #[test]
+    fn spacing_sets_overlap() {
+        assert_eq!(hand().spacing(14).width(), 14 * 3);
+        assert_eq!(hand().spacing(0).width(), 14);
+        let hand = hand().spacing(2);
+        assert_eq!(hand.width(), 2 * 2 + 14);
+        let area = Rect::new(0, 0, 18, 10);
+        let state = HandState::default();
+        assert_eq!(hand.card_at(area, &state, Position::new(1, 5)), Some(0));
+        assert_eq!(hand.card_at(area, &state, Position::new(2, 5)), Some(1));
+        assert_eq!(hand.card_at(area, &state, Position::new(4, 5)), Some(2));
+        // fitting to a wider width keeps the spacing
+        assert_eq!(hand.fit_to_width(200).width(), 2 * 2 + 14);
+    }
+
+    #[test]
+    fn scroll_clips_the_left_edge() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 10));
+        Widget::render(&hand().scroll(6), buf.area, &mut buf);
+        // the first card is hidden, and the second shows its right part
+        assert_eq!(buf[(0, 1)].symbol(), "─");
+        assert_eq!(buf[(2, 1)].symbol(), "╭");
+        assert_eq!(buf[(15, 9)].symbol(), "╯");
+        assert_eq!(buf[(2, 9)].symbol(), "╰");
+    }
+
+    #[test]
+    fn scroll_matches_unscrolled_render() {
+        let hand = Hand::new([Card::new(Rank::Ace, Suit::Hearts)]);
+        let mut full = Buffer::empty(Rect::new(0, 0, 14, 10));
+        Widget::render(&hand, full.area, &mut full);
+        let mut blanked = 0;
+        for scroll in 1..14 {
+            let mut scrolled = Buffer::empty(Rect::new(0, 0, 14 - scroll, 10));
+            Widget::render(&hand.clone().scroll(scroll), scrolled.area, &mut scrolled);
+            for position in scrolled.area.positions() {
+                // wide symbols cut in half by the left edge are blanked
+                let cut = &full[(scroll - 1, position.y)];
+                if position.x == 0 && cut.symbol().width() > 1 {
+                    assert_eq!(scrolled[position].symbol(), " ");
+                    assert_eq!(scrolled[position].fg, cut.fg);
+                    blanked += 1;
+                    continue;
+                }
+                let expected = &full[(position.x + scroll, position.y)];
+                assert_eq!(&scrolled[position], expected, "{scroll} {position}");
+            }
+        }
+        assert_eq!(blanked, 1);
+    }
+
+    #[test]
+    fn card_at_accounts_for_scroll() {
+        let hand = hand().scroll(6);
+        let area = Rect::new(0, 0, 16, 10);
+        let state = HandState::default();
+        let at = |x, y| hand.card_at(area, &state, Position::new(x, y));
+        assert_eq!(at(0, 5), Some(1));
+        assert_eq!(at(1, 5), Some(1));
+        assert_eq!(at(2, 5), Some(2));
+        assert_eq!(at(15, 5), Some(2));
+    }
+
+    #[test]
+    fn scroll_past_every_card_renders_nothing() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 10));
+        Widget::render(&hand().scroll(22), buf.area, &mut buf);
+        assert_eq!(buf, Buffer::empty(buf.area));
+    }
+
+    #[test]
+    fn fit_to_width_spreads_cards_evenly() {
+        let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades); 10]).fit_to_width(40);
+        // (40 - 14) / 9 columns between cards
+        assert_eq!(hand.width(), 9 * 2 + 14);
+        let area = Rect::new(0, 0, 40, 10);
+        let state = HandState::default();
+        assert_eq!(hand.card_at(area, &state, Position::new(1, 5)), Some(0));
+        assert_eq!(hand.card_at(area, &state, Position::new(2, 5)), Some(1));
+        assert_eq!(hand.card_at(area, &state, Position::new(18, 5)), Some(9));
+    }
+
+    #[test]
+    fn fit_to_width_fits_many_cards() {
+        let cards = crate::Deck::standard().cards().to_vec();
+        let hand = Hand::new(cards).fit_to_width(80);
+        assert_eq!(hand.width(), 51 + 14);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 10));
+        Widget::render(&hand, buf.area, &mut buf);
+        assert_eq!(buf[(64, 9)].symbol(), "╯");
+        assert_eq!(buf[(65, 9)].symbol(), " ");
+    }
+
+    #[test]
+    fn fit_to_width_never_widens_the_spacing() {
+        assert_eq!(hand().fit_to_width(200).width(), 4 * 2 + 14);
+        assert_eq!(hand().spacing(20).fit_to_width(200).width(), 20 * 2 + 14);
+        assert_eq!(hand().spacing(20).fit_to_width(30).width(), 8 * 2 + 14);
+    }
+
+    #[test]
+    fn tightens_a_hand_wider_than_the_area() {
+        let hand = Hand::new(crate::Deck::standard().cards()[..13].iter().copied());
+        assert_eq!(hand.width(), 12 * 4 + 14);
+        let area = Rect::new(0, 0, 38, 10);
+        let mut buf = Buffer::empty(area);
+        Widget::render(&hand, area, &mut buf);
+        // (38 - 14) / 12 columns between cards, so the last card ends at the right edge
+        let expected = hand.clone().spacing(2);
+        let mut expected_buf = Buffer::empty(area);
+        Widget::render(&expected, area, &mut expected_buf);
+        assert_eq!(buf, expected_buf);
+        assert_eq!(buf[(37, 9)].symbol(), "╯");
+        let state = HandState::default();
+        assert_eq!(hand.card_at(area, &state, Position::new(36, 5)), Some(12));
+        assert_eq!(hand.card_at(area, &state, Position::new(3, 5)), Some(1));
+        // a hand that fits keeps its spacing
+        let wide = Rect::new(0, 0, 80, 10);
+        assert_eq!(hand.card_at(wide, &state, Position::new(60, 5)), Some(12));
+    }
+
+    #[test]
+    fn fit_to_width_clips_when_too_narrow() {
+        let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades); 30]).fit_to_width(20);
+        assert_eq!(hand.width(), 29 + 14);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+        Widget::render(&hand, buf.area, &mut buf);
+        // the twentieth card starts at the right edge, and the rest are clipped
+        assert_eq!(buf[(19, 1)].symbol(), "╭");
+        assert_eq!(buf[(19, 9)].symbol(), "╰");
+    }
+
+    #[test]
+    fn fit_to_width_single_card() {
+        let hand = Hand::new([Card::new(Rank::Ace, Suit::Spades)]).fit_to_width(5);
+        assert_eq!(hand.width(), 14);
+    }
+
+    #[test]
+    fn fit_to_width_empty_hand() {
+        let hand = Hand::default().fit_to_width(10);
+        assert_eq!(hand.width(), 0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 10));
+        Widget::render(&hand, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::empty(buf.area));
+    }
+
+    #[test]
+    fn renders_unselected_cards_below_headroom() {
+        let hand = hand();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 10));
+        Widget::render(&hand, buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+        assert_eq!(buf[(0, 1)].symbol(), "╭");
+        assert_eq!(buf[(8, 1)].symbol(), "╭");
+        assert_eq!(buf[(21, 9)].symbol(), "╯");
+    }
+
+    #[test]
+    fn empty_hand_renders_nothing() {
+        let hand = Hand::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        let mut state = HandState::default();
+        state.select(Some(0));
+        StatefulWidget::render(&hand, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::empty(buf.area));
+        assert_eq!(hand.width(), 0);
+    }
+
+    #[test]
+    fn empty_hand_renders_placeholder() {
+        let hand = Hand::default().empty_placeholder(Some(Text::raw("no cards")));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        Widget::render(&hand, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["no cards  ", "          "]));
+        // the placeholder is not shown once the hand has cards
+        let placeholder = Some(Text::raw("no cards"));
+        let mut with_placeholder = Buffer::empty(Rect::new(0, 0, 22, 10));
+        let mut without = with_placeholder.clone();
+        Widget::render(
+            &self::hand().empty_placeholder(placeholder),
+            with_placeholder.area,
+            &mut with_placeholder,
+        );
+        Widget::render(&self::hand(), without.area, &mut without);
+        assert_eq!(with_placeholder, without);
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use ratatui_core::layout::Size;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{CardSize, Suit};
+
+/// The color of a joker [`Card`], created with [`Card::joker`].
+///
+/// Decks with two jokers usually print one in color and one in black, so the color tells them
+/// apart. Jokers are not a [`Rank`], so iterating over the ranks, and [`Deck::standard`], never
+/// include them.
+///
+/// [`Card`]: crate::Card
+/// [`Card::joker`]: crate::Card::joker
+/// [`Rank`]: crate::Rank
+/// [`Deck::standard`]: crate::Deck::standard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JokerColor {
+    /// The joker drawn in the color of the red suits.
+    Red,
+    /// The joker drawn in the color of the black suits.
+    Black,
+}
+
+impl JokerColor {
+    /// Returns the suit whose color the joker is drawn in, which is also the placeholder suit of a
+    /// joker card: Hearts for the red joker and Spades for the black joker.
+    pub const fn suit(self) -> Suit {
+        match self {
+            Self::Red => Suit::Hearts,
+            Self::Black => Suit::Spades,
+        }
+    }
+
+    /// Returns the Unicode playing card character for the joker.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::JokerColor;
+    ///
+    /// assert_eq!(JokerColor::Red.as_unicode_char(), '🂿');
+    /// assert_eq!(JokerColor::Black.as_unicode_char(), '🃏');
+    /// ```
+    pub const fn as_unicode_char(self) -> char {
+        match self {
+            Self::Red => '\u{1F0BF}',
+            Self::Black => '\u{1F0CF}',
+        }
+    }
+}
+
+/// The label printed in the corners of a joker card.
+pub(crate) const JOKER_LABEL: &str = "JOKER";
+
+/// The jester drawn in the middle of a joker card, two columns wide like a suit symbol.
+pub(crate) const JESTER_PIP: &str = "🃏";
+
+/// The faces of joker cards of the sizes drawn from lines, one line per row, built once on first
+/// use so that rendering a joker only looks its face up.
+static JOKER_FACES: LazyLock<HashMap<CardSize, String>> = LazyLock::new(|| {
+    CardSize::iter()
+        .filter(|size| !matches!(size, CardSize::Glyph | CardSize::Tiny))
+        .map(|size| (size, joker_lines(size).join("\n")))
+        .collect()
+});
+
+/// Returns the face of a joker card of the given size, one line per row, as built by
+/// [`joker_lines`].
+pub(crate) fn joker_face(size: CardSize) -> &'static str {
+    JOKER_FACES.get(&size).map_or("", String::as_str)
+}
+
+/// Returns the lines of the face of a joker card of the given size: the card's border with the
+/// label in the top left and bottom right corners and the jester in the middle.
+///
+/// Compact cards have no border, so they show the label and the jester above their underline.
+/// Tiny cards are not drawn from lines, so they are left out.
+fn joker_lines(size: CardSize) -> Vec<String> {
+    let Size { width, height } = size.dimensions();
+    let width = usize::from(width);
+    if size == CardSize::Compact {
+        let fill = width.saturating_sub(JOKER_LABEL.len() + 2);
+        return vec![
+            format!("{JOKER_LABEL}{JESTER_PIP}{}", " ".repeat(fill)),
+            "─".repeat(width),
+        ];
+    }
+    let inner = width.saturating_sub(2);
+    let label_fill = inner.saturating_sub(JOKER_LABEL.len());
+    let left = inner.saturating_sub(2) / 2;
+    let right = inner.saturating_sub(2 + left);
+    let last = usize::from(height).saturating_sub(1);
+    (0..=last)
+        .map(|row| match row {
+            0 => format!("╭{}╮", "─".repeat(inner)),
+            row if row == last => format!("╰{}╯", "─".repeat(inner)),
+            1 => format!("│{JOKER_LABEL}{}│", " ".repeat(label_fill)),
+            row if row == last - 1 => format!("│{}{JOKER_LABEL}│", " ".repeat(label_fill)),
+            row if row == last / 2 => {
+                format!("│{}{JESTER_PIP}{}│", " ".repeat(left), " ".repeat(right))
+            }
+            _ => format!("│{}│", " ".repeat(inner)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+    use unicode_width::UnicodeWidthStr;
+
+    use super::*;
+
+    #[test]
+    fn joker_lines_match_dimensions() {
+        for size in
+            CardSize::iter().filter(|size| !matches!(size, CardSize::Glyph | CardSize::Tiny))
+        {
+            let Size { width, height } = size.dimensions();
+            let lines = joker_lines(size);
+            assert_eq!(lines.len(), usize::from(height), "{size:?}");
+            for line in lines {
+                assert_eq!(line.width(), usize::from(width), "{size:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn small_joker() {
+        assert_eq!(
+            joker_lines(CardSize::Small),
+            ["╭──────╮", "│JOKER │", "│  🃏  │", "│ JOKER│", "╰──────╯"]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use itertools::Itertools;
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+use ratatui_core::widgets::Widget;
+use strum::IntoEnumIterator;
+
+use crate::{Suit, SuitTheme};
+
+/// A compact row showing each suit's symbol in its theme color, e.g. for a settings screen or a
+/// tutorial.
+///
+/// The symbols use the text presentation ([`Suit::as_text_symbol`]), as emoji ignore the color
+/// they are drawn in. The suit names can be shown beside the symbols with [`SuitLegend::names`].
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{SuitLegend, SuitTheme};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let legend = SuitLegend::new(SuitTheme::colorblind_friendly()).names(true);
+/// frame.render_widget(&legend, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SuitLegend {
+    /// The colors the suits are shown in.
+    pub theme: SuitTheme,
+    /// Whether each symbol is followed by the suit's name. Defaults to `false`.
+    pub names: bool,
+}
+
+impl SuitLegend {
+    /// Creates a legend for the given suit colors.
+    pub const fn new(theme: SuitTheme) -> Self {
+        Self {
+            theme,
+            names: false,
+        }
+    }
+
+    /// Sets whether each symbol is followed by the suit's name, e.g. `♠ Spades`.
+    #[must_use]
+    pub const fn names(mut self, names: bool) -> Self {
+        self.names = names;
+        self
+    }
+
+    /// Returns the legend as a line, for composing with other text.
+    pub fn to_line(&self) -> Line<'static> {
+        let separator = if self.names { "  " } else { " " };
+        let symbols = Suit::iter().map(|suit| {
+            let style = Style::new().fg(self.theme.color(suit));
+            let mut text = suit.as_text_symbol().to_string();
+            if self.names {
+                text = format!("{text} {suit}");
+            }
+            Span::styled(text, style)
+        });
+        let spans = Itertools::intersperse(symbols, Span::raw(separator));
+        Line::from(spans.collect::<Vec<_>>())
+    }
+}
+
+impl Widget for &SuitLegend {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.to_line().render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn renders_symbols_in_theme_colors() {
+        let legend = SuitLegend::new(SuitTheme::two_color());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        legend.render(buf.area, &mut buf);
+        let symbols: Vec<_> = (0..8).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(
+            symbols,
+            [
+                Suit::Spades.as_text_symbol(),
+                " ",
+                Suit::Hearts.as_text_symbol(),
+                " ",
+                Suit::Diamonds.as_text_symbol(),
+                " ",
+                Suit::Clubs.as_text_symbol(),
+                " ",
+            ]
+        );
+        assert_eq!(buf[(0, 0)].fg, Color::Black);
+        assert_eq!(buf[(2, 0)].fg, Color::Red);
+        assert_eq!(buf[(4, 0)].fg, Color::Red);
+        assert_eq!(buf[(6, 0)].fg, Color::Black);
+    }
+
+    #[test]
+    fn names_follow_symbols() {
+        let line = SuitLegend::default().names(true).to_line();
+        assert_eq!(
+            line.to_string(),
+            "\u{2660}\u{FE0E} Spades  \u{2665}\u{FE0E} Hearts  \u{2666}\u{FE0E} Diamonds  \
+             \u{2663}\u{FE0E} Clubs"
+        );
+        assert_eq!(line.spans[2].style.fg, Some(Color::Red));
+    }
+}
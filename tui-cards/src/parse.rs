@@ -0,0 +1,295 @@
+//! Parsing cards, ranks, and suits from strings.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+use crate::{Card, CardSize, JokerColor, Rank, Suit};
+
+/// An error returned when parsing a card, rank, or suit from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCardError {
+    /// The input was empty (or only whitespace).
+    Empty,
+    /// The input does not name a rank.
+    InvalidRank(String),
+    /// The input does not name a suit.
+    InvalidSuit(String),
+    /// The input is a rank with no suit after it.
+    MissingSuit(String),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse an empty string"),
+            Self::InvalidRank(input) => write!(f, "invalid rank: {input:?}"),
+            Self::InvalidSuit(input) => write!(f, "invalid suit: {input:?}"),
+            Self::MissingSuit(input) => write!(f, "missing suit after rank: {input:?}"),
+        }
+    }
+}
+
+impl Error for ParseCardError {}
+
+/// Parses a suit from its name, an abbreviation, or its symbol.
+///
+/// Parsing ignores case and surrounding whitespace. The following forms are accepted:
+///
+/// - any prefix of the English name, e.g. `"S"`, `"sp"`, `"heart"`, or `"Diamonds"`. Every suit
+///   name starts with a different letter, so prefixes are never ambiguous.
+/// - the suit symbol in any of the forms the crate produces, e.g. `"♠"`, [`Suit::as_text_symbol`],
+///   or [`Suit::as_colored_symbol`].
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::Suit;
+///
+/// assert_eq!("h".parse(), Ok(Suit::Hearts));
+/// assert_eq!("di".parse(), Ok(Suit::Diamonds));
+/// assert_eq!("Clubs".parse(), Ok(Suit::Clubs));
+/// assert_eq!("♠".parse(), Ok(Suit::Spades));
+/// assert!("x".parse::<Suit>().is_err());
+/// ```
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        if input.is_empty() {
+            return Err(ParseCardError::Empty);
+        }
+        let lowercase = input.to_lowercase();
+        Self::iter()
+            .find(|suit| {
+                suit.to_string().to_lowercase().starts_with(&lowercase)
+                    || input.trim_end_matches(['\u{FE0E}', '\u{FE0F}'])
+                        == suit.as_symbol().to_string()
+                    || input == suit.as_four_color_symbol()
+            })
+            .ok_or_else(|| ParseCardError::InvalidSuit(input.to_string()))
+    }
+}
+
+/// Parses a rank from its short name, its symbol, or its name.
+///
+/// Parsing ignores case and surrounding whitespace. The following forms are accepted:
+///
+/// - the short name printed in the corners of a card, [`Rank::short_name`], e.g. `"A"`, `"7"`,
+///   `"10"`, or `"q"`.
+/// - the single character symbol, [`Rank::as_symbol`], which writes the Ten as `"T"`.
+/// - the full English name, [`Rank::long_name`], e.g. `"Ace"` or `"seven"`. Unlike suits,
+///   prefixes of the names are not accepted, as several names start with the same letters.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::Rank;
+///
+/// assert_eq!("10".parse(), Ok(Rank::Ten));
+/// assert_eq!("t".parse(), Ok(Rank::Ten));
+/// assert_eq!("King".parse(), Ok(Rank::King));
+/// assert!("1".parse::<Rank>().is_err());
+/// ```
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        if input.is_empty() {
+            return Err(ParseCardError::Empty);
+        }
+        Self::iter()
+            .find(|rank| {
+                input.eq_ignore_ascii_case(rank.short_name())
+                    || input.eq_ignore_ascii_case(rank.long_name())
+                    || input.eq_ignore_ascii_case(&rank.as_symbol().to_string())
+            })
+            .ok_or_else(|| ParseCardError::InvalidRank(input.to_string()))
+    }
+}
+
+/// Parses a card from its rank followed by its suit, e.g. `"AS"` or `"10h"`.
+///
+/// The rank is a single character, or `10`, in any of the forms [`Rank`] parses, and the suit is
+/// anything [`Suit`] parses, such as a letter, a name, or a symbol. Parsing ignores case and
+/// whitespace around and between the two. The card has the default size and presentation of
+/// [`Card::new`].
+///
+/// `Red Joker` and `Black Joker`, as jokers are [displayed](Card#impl-Display-for-Card), parse
+/// into a [`CardSize::Normal`] joker of that color.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{Card, Rank, Suit};
+///
+/// assert_eq!("AS".parse(), Ok(Card::new(Rank::Ace, Suit::Spades)));
+/// assert_eq!("10h".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+/// assert_eq!("T♥".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+/// assert!("1S".parse::<Card>().is_err());
+/// ```
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        let Some(first) = input.chars().next() else {
+            return Err(ParseCardError::Empty);
+        };
+        if let Some(color) =
+            JokerColor::iter().find(|color| input.eq_ignore_ascii_case(&format!("{color} Joker")))
+        {
+            return Ok(Self::joker(color, CardSize::Normal));
+        }
+        let rank_len = if input.starts_with("10") {
+            2
+        } else {
+            first.len_utf8()
+        };
+        let (rank, suit) = input.split_at(rank_len);
+        let rank = rank
+            .parse()
+            .map_err(|_| ParseCardError::InvalidRank(rank.to_string()))?;
+        let suit = suit.trim();
+        if suit.is_empty() {
+            return Err(ParseCardError::MissingSuit(input.to_string()));
+        }
+        Ok(Self::new(rank, suit.parse()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn parse_suit_letters() {
+        assert_eq!("s".parse(), Ok(Suit::Spades));
+        assert_eq!("H".parse(), Ok(Suit::Hearts));
+        assert_eq!("d".parse(), Ok(Suit::Diamonds));
+        assert_eq!("C".parse(), Ok(Suit::Clubs));
+    }
+
+    #[test]
+    fn parse_suit_abbreviations() {
+        assert_eq!("sp".parse(), Ok(Suit::Spades));
+        assert_eq!("he".parse(), Ok(Suit::Hearts));
+        assert_eq!("Di".parse(), Ok(Suit::Diamonds));
+        assert_eq!("CL".parse(), Ok(Suit::Clubs));
+    }
+
+    #[test]
+    fn parse_suit_names() {
+        assert_eq!("spades".parse(), Ok(Suit::Spades));
+        assert_eq!("Heart".parse(), Ok(Suit::Hearts));
+        assert_eq!(" DIAMONDS ".parse(), Ok(Suit::Diamonds));
+        assert_eq!("club".parse(), Ok(Suit::Clubs));
+    }
+
+    #[test]
+    fn parse_suit_symbols() {
+        for suit in Suit::iter() {
+            assert_eq!(suit.as_symbol().to_string().parse(), Ok(suit));
+            assert_eq!(suit.as_text_symbol().parse(), Ok(suit));
+            assert_eq!(suit.as_colored_symbol().parse(), Ok(suit));
+            assert_eq!(suit.as_four_color_symbol().parse(), Ok(suit));
+        }
+    }
+
+    #[test]
+    fn parse_suit_errors() {
+        assert_eq!("".parse::<Suit>(), Err(ParseCardError::Empty));
+        assert_eq!("  ".parse::<Suit>(), Err(ParseCardError::Empty));
+        assert_eq!(
+            "x".parse::<Suit>(),
+            Err(ParseCardError::InvalidSuit("x".to_string()))
+        );
+        assert_eq!(
+            "spadesx".parse::<Suit>(),
+            Err(ParseCardError::InvalidSuit("spadesx".to_string()))
+        );
+        assert_eq!(
+            "hs".parse::<Suit>(),
+            Err(ParseCardError::InvalidSuit("hs".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_rank_forms() {
+        for rank in Rank::iter() {
+            assert_eq!(rank.short_name().parse(), Ok(rank));
+            assert_eq!(rank.short_name().to_lowercase().parse(), Ok(rank));
+            assert_eq!(rank.long_name().to_uppercase().parse(), Ok(rank));
+            assert_eq!(rank.as_symbol().to_string().parse(), Ok(rank));
+        }
+        assert_eq!(" t ".parse(), Ok(Rank::Ten));
+    }
+
+    #[test]
+    fn parse_rank_errors() {
+        assert_eq!("".parse::<Rank>(), Err(ParseCardError::Empty));
+        for input in ["1", "11", "X", "Kin", "Ace of Spades"] {
+            assert_eq!(
+                input.parse::<Rank>(),
+                Err(ParseCardError::InvalidRank(input.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_card() {
+        assert_eq!("AS".parse(), Ok(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!("10H".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+        assert_eq!("th".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+        assert_eq!("qc".parse(), Ok(Card::new(Rank::Queen, Suit::Clubs)));
+        assert_eq!(
+            " 7 diamonds ".parse(),
+            Ok(Card::new(Rank::Seven, Suit::Diamonds))
+        );
+        assert_eq!(
+            "black joker".parse(),
+            Ok(Card::joker(JokerColor::Black, CardSize::Normal))
+        );
+        for card in Rank::iter().cartesian_product(Suit::iter()) {
+            let (rank, suit) = card;
+            let compact = format!("{}{}", rank.short_name(), suit.as_symbol());
+            assert_eq!(compact.parse(), Ok(Card::new(rank, suit)));
+        }
+    }
+
+    #[test]
+    fn parse_card_errors() {
+        assert_eq!("".parse::<Card>(), Err(ParseCardError::Empty));
+        assert_eq!(
+            "1S".parse::<Card>(),
+            Err(ParseCardError::InvalidRank("1".to_string()))
+        );
+        assert_eq!(
+            "XZ".parse::<Card>(),
+            Err(ParseCardError::InvalidRank("X".to_string()))
+        );
+        assert_eq!(
+            "AZ".parse::<Card>(),
+            Err(ParseCardError::InvalidSuit("Z".to_string()))
+        );
+        assert_eq!(
+            "10".parse::<Card>(),
+            Err(ParseCardError::MissingSuit("10".to_string()))
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            ParseCardError::InvalidSuit("x".to_string()).to_string(),
+            "invalid suit: \"x\""
+        );
+    }
+}
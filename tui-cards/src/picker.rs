@@ -0,0 +1,208 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use strum::IntoEnumIterator;
+
+use crate::{Card, CardSize, CardTheme, Rank, Suit};
+
+/// A widget for choosing a card by stepping through ranks and suits.
+///
+/// The picker renders a live preview of the chosen card, with a row of every rank and a row of
+/// every suit below it in which the current choice is highlighted. The choice is held in a
+/// [`PickerState`], which applications update from key presses, e.g. with
+/// [`PickerState::next_rank`] and [`PickerState::next_suit`].
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{CardPicker, PickerState};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let mut state = PickerState::default();
+/// state.next_suit();
+/// frame.render_stateful_widget(&CardPicker::new(), frame.area(), &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardPicker {
+    /// The size of the preview card. Defaults to [`CardSize::Normal`].
+    pub size: CardSize,
+    /// The colors the preview card is rendered with.
+    pub theme: CardTheme,
+    /// The style of the current rank and suit in the selector rows. Defaults to reversed.
+    pub selected_style: Style,
+}
+
+/// The card chosen in a [`CardPicker`]. Defaults to the Ace of Spades.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PickerState {
+    pub rank: Rank,
+    pub suit: Suit,
+}
+
+impl CardPicker {
+    /// Creates a picker with a normal size preview.
+    pub const fn new() -> Self {
+        Self {
+            size: CardSize::Normal,
+            theme: CardTheme::new(),
+            selected_style: Style::new().reversed(),
+        }
+    }
+
+    /// Sets the size of the preview card.
+    #[must_use]
+    pub const fn size(mut self, size: CardSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the colors the preview card is rendered with.
+    #[must_use]
+    pub const fn theme(mut self, theme: CardTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the style of the current rank and suit in the selector rows.
+    #[must_use]
+    pub fn selected_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selected_style = style.into();
+        self
+    }
+}
+
+impl Default for CardPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PickerState {
+    /// Returns the chosen card.
+    pub const fn card(&self) -> Card {
+        Card::new(self.rank, self.suit)
+    }
+
+    /// Moves to the next rank, wrapping from King to Ace.
+    pub const fn next_rank(&mut self) {
+        self.rank = self.rank.rotate();
+    }
+
+    /// Moves to the next suit, wrapping from Clubs to Spades.
+    pub const fn next_suit(&mut self) {
+        self.suit = self.suit.rotate();
+    }
+}
+
+impl Default for PickerState {
+    fn default() -> Self {
+        Self {
+            rank: Rank::Ace,
+            suit: Suit::Spades,
+        }
+    }
+}
+
+impl StatefulWidget for &CardPicker {
+    type State = PickerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let card = state.card().size(self.size).theme(self.theme);
+        let height = self.size.dimensions().height.min(area.height);
+        card.render(Rect { height, ..area }, buf);
+
+        let ranks: Vec<_> = Rank::iter()
+            .map(|rank| {
+                let span = Span::raw(rank.short_name());
+                if rank == state.rank {
+                    span.style(self.selected_style)
+                } else {
+                    span
+                }
+            })
+            .flat_map(|span| [span, Span::raw(" ")])
+            .collect();
+        let suits: Vec<_> = Suit::iter()
+            .map(|suit| {
+                let span = Span::raw(suit.as_symbol().to_string());
+                if suit == state.suit {
+                    span.style(self.selected_style)
+                } else {
+                    span
+                }
+            })
+            .flat_map(|span| [span, Span::raw(" ")])
+            .collect();
+        let selectors = Rect {
+            y: area.y.saturating_add(height),
+            height: area.height - height,
+            ..area
+        };
+        for (line, row) in [Line::from(ranks), Line::from(suits)]
+            .into_iter()
+            .zip(selectors.rows())
+        {
+            line.render(row, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Modifier;
+
+    use super::*;
+
+    #[test]
+    fn next_rank_and_suit_wrap() {
+        let mut state = PickerState {
+            rank: Rank::King,
+            suit: Suit::Clubs,
+        };
+        state.next_rank();
+        state.next_suit();
+        assert_eq!(state.card(), Card::new(Rank::Ace, Suit::Spades));
+    }
+
+    #[test]
+    fn renders_preview_and_selectors() {
+        let picker = CardPicker::new().size(CardSize::Small);
+        let mut state = PickerState {
+            rank: Rank::Ten,
+            suit: Suit::Hearts,
+        };
+        let mut buf = Buffer::empty(Rect::new(0, 0, 28, 7));
+        StatefulWidget::render(&picker, buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(1, 1)].symbol(), "1");
+        assert_eq!(buf[(2, 1)].symbol(), "0");
+        let ranks: String = (0..28).map(|x| buf[(x, 5)].symbol().to_string()).collect();
+        assert_eq!(ranks, "A 2 3 4 5 6 7 8 9 10 J Q K  ");
+        let suits: String = (0..8).map(|x| buf[(x, 6)].symbol().to_string()).collect();
+        assert_eq!(suits, "♠ ♥ ♦ ♣ ");
+        assert!(buf[(18, 5)].modifier.contains(Modifier::REVERSED));
+        assert!(buf[(19, 5)].modifier.contains(Modifier::REVERSED));
+        assert!(!buf[(20, 5)].modifier.contains(Modifier::REVERSED));
+        assert!(buf[(2, 6)].modifier.contains(Modifier::REVERSED));
+        assert!(!buf[(0, 6)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn renders_clipped_to_area() {
+        let picker = CardPicker::new();
+        let mut state = PickerState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 20));
+        StatefulWidget::render(&picker, Rect::new(0, 0, 10, 4), &mut buf, &mut state);
+        for position in buf.area.positions() {
+            if !Rect::new(0, 0, 10, 4).contains(position) {
+                assert_eq!(buf[position].symbol(), " ");
+            }
+        }
+    }
+}
@@ -0,0 +1,1003 @@
+use std::cmp::Ordering;
+
+use indoc::indoc;
+use strum::{Display, EnumCount, EnumIter};
+
+use crate::CardSize;
+
+/// The rank of a playing card.
+///
+/// # Ordering
+///
+/// The derived [`Ord`] treats the Ace as low, following the declaration order from Ace to King.
+/// Games where the Ace ranks above the King can compare ranks with [`Rank::cmp_with`] and
+/// [`AceOrder::High`] instead.
+///
+/// # Matching
+///
+/// The enum is `#[non_exhaustive]` so that ranks such as a Knight can be added without breaking
+/// downstream code, which means matches on it must include a wildcard arm. Prefer the methods that
+/// already encode the per rank logic, such as [`Rank::value`], [`Rank::as_symbol`],
+/// [`Rank::is_number`], and [`Rank::is_face`], over matching on the variants yourself.
+///
+/// ```rust
+/// use tui_cards::Rank;
+///
+/// fn blackjack_value(rank: Rank) -> u8 {
+///     if rank.is_face() {
+///         10
+///     } else {
+///         rank.value(Default::default())
+///     }
+/// }
+/// assert_eq!(blackjack_value(Rank::Queen), 10);
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display, EnumCount, EnumIter,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Rank {
+    Ace,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+}
+
+/// Whether the Ace ranks below the Two or above the King.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cmp::Ordering;
+///
+/// use tui_cards::{AceOrder, Rank};
+///
+/// assert_eq!(
+///     Rank::Ace.cmp_with(Rank::King, AceOrder::Low),
+///     Ordering::Less
+/// );
+/// assert_eq!(
+///     Rank::Ace.cmp_with(Rank::King, AceOrder::High),
+///     Ordering::Greater
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AceOrder {
+    /// The Ace is the lowest rank, with a value of 1. This matches the derived [`Ord`] for
+    /// [`Rank`].
+    #[default]
+    Low,
+    /// The Ace is the highest rank, with a value of 14.
+    High,
+}
+
+impl Rank {
+    /// Every rank, from Ace to King.
+    pub const ALL: [Self; Self::COUNT] = [
+        Self::Ace,
+        Self::Two,
+        Self::Three,
+        Self::Four,
+        Self::Five,
+        Self::Six,
+        Self::Seven,
+        Self::Eight,
+        Self::Nine,
+        Self::Ten,
+        Self::Jack,
+        Self::Queen,
+        Self::King,
+    ];
+
+    /// The number (pip) ranks, Two to Ten.
+    ///
+    /// The Ace is neither a number nor a face rank; add it separately when a game counts it as
+    /// either.
+    pub const ALL_NUMBERS: [Self; 9] = [
+        Self::Two,
+        Self::Three,
+        Self::Four,
+        Self::Five,
+        Self::Six,
+        Self::Seven,
+        Self::Eight,
+        Self::Nine,
+        Self::Ten,
+    ];
+
+    /// The face ranks: Jack, Queen, and King.
+    pub const ALL_FACES: [Self; 3] = [Self::Jack, Self::Queen, Self::King];
+
+    /// The court ranks: Jack, Queen, and King.
+    ///
+    /// This is the same as [`Rank::ALL_FACES`], for games that call them court cards.
+    pub const ALL_COURTS: [Self; 3] = Self::ALL_FACES;
+
+    /// Returns true for the number ranks, Two to Ten. See [`Rank::ALL_NUMBERS`].
+    pub const fn is_number(self) -> bool {
+        matches!(
+            self,
+            Self::Two
+                | Self::Three
+                | Self::Four
+                | Self::Five
+                | Self::Six
+                | Self::Seven
+                | Self::Eight
+                | Self::Nine
+                | Self::Ten
+        )
+    }
+
+    /// Returns true for the face ranks, Jack, Queen, and King. See [`Rank::ALL_FACES`].
+    pub const fn is_face(self) -> bool {
+        matches!(self, Self::Jack | Self::Queen | Self::King)
+    }
+
+    /// Returns true for the court ranks, Jack, Queen, and King. This is the same as
+    /// [`Rank::is_face`]. See [`Rank::ALL_COURTS`].
+    pub const fn is_court(self) -> bool {
+        self.is_face()
+    }
+
+    /// Returns the numeric value of the rank: 2 to 10 for the number ranks, 11 to 13 for the
+    /// Jack, Queen, and King, and 1 or 14 for the Ace depending on the order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{AceOrder, Rank};
+    ///
+    /// assert_eq!(Rank::Seven.value(AceOrder::Low), 7);
+    /// assert_eq!(Rank::Ace.value(AceOrder::High), 14);
+    /// ```
+    pub const fn value(self, order: AceOrder) -> u8 {
+        match (self, order) {
+            (Self::Ace, AceOrder::High) => 14,
+            (rank, _) => rank as u8 + 1,
+        }
+    }
+
+    /// Returns the rank with the given [`Rank::value`] in the given order, or `None` if no rank has
+    /// that value.
+    ///
+    /// This is the inverse of [`Rank::value`], so it is unambiguous. Scoring systems where several
+    /// ranks share a value, such as blackjack counting the Ten and every face card as 10, cannot be
+    /// inverted this way and need to keep the rank alongside the score.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{AceOrder, Rank};
+    ///
+    /// assert_eq!(Rank::from_value(1, AceOrder::Low), Some(Rank::Ace));
+    /// assert_eq!(Rank::from_value(1, AceOrder::High), None);
+    /// ```
+    pub const fn from_value(value: u8, order: AceOrder) -> Option<Self> {
+        match (value, order) {
+            (1, AceOrder::Low) | (14, AceOrder::High) => Some(Self::Ace),
+            (2..=13, _) => Some(Self::ALL[value as usize - 1]),
+            _ => None,
+        }
+    }
+
+    /// Returns the rank for a poker order value from 2 to 14, where the Ace is 14.
+    ///
+    /// This is the same as [`Rank::from_value`] with [`AceOrder::High`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Rank;
+    ///
+    /// assert_eq!(Rank::from_poker_order(14), Some(Rank::Ace));
+    /// assert_eq!(Rank::from_poker_order(11), Some(Rank::Jack));
+    /// assert_eq!(Rank::from_poker_order(1), None);
+    /// ```
+    pub const fn from_poker_order(value: u8) -> Option<Self> {
+        Self::from_value(value, AceOrder::High)
+    }
+
+    /// Returns the value of the rank with the Ace high: 2 to 13 for Two to King, and 14 for the
+    /// Ace. This is the same as [`Rank::value`] with [`AceOrder::High`].
+    pub const fn value_ace_high(self) -> u8 {
+        self.value(AceOrder::High)
+    }
+
+    /// Returns the value of the rank with the Ace low: 1 for the Ace, and 2 to 13 for Two to King.
+    /// This is the same as [`Rank::value`] with [`AceOrder::Low`].
+    pub const fn value_ace_low(self) -> u8 {
+        self.value(AceOrder::Low)
+    }
+
+    /// Compares two ranks, placing the Ace according to the given order.
+    pub fn cmp_with(self, other: Self, order: AceOrder) -> Ordering {
+        self.value(order).cmp(&other.value(order))
+    }
+
+    /// Compares two ranks with the Ace above the King, e.g. to sort poker hands.
+    ///
+    /// The derived [`Ord`] places the Ace below the Two instead. This is the same as
+    /// [`Rank::cmp_with`] with [`AceOrder::High`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    ///
+    /// use tui_cards::Rank;
+    ///
+    /// let mut ranks = [Rank::Ace, Rank::King, Rank::Two];
+    /// ranks.sort_by(|a, b| a.cmp_ace_high(*b));
+    /// assert_eq!(ranks, [Rank::Two, Rank::King, Rank::Ace]);
+    /// assert_eq!(Rank::Ace.cmp(&Rank::Two), Ordering::Less);
+    /// ```
+    pub fn cmp_ace_high(self, other: Self) -> Ordering {
+        self.cmp_with(other, AceOrder::High)
+    }
+
+    /// Returns the next rank, cycling from Ace to King and back to Ace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Rank;
+    ///
+    /// assert_eq!(Rank::Ten.rotate(), Rank::Jack);
+    /// assert_eq!(Rank::King.rotate(), Rank::Ace);
+    /// ```
+    #[must_use]
+    pub const fn rotate(self) -> Self {
+        Self::ALL[(self as usize + 1) % Self::COUNT]
+    }
+
+    /// Returns the position of the rank within a suit's row of the Unicode Playing Cards block,
+    /// from `0x1` for the Ace to `0xE` for the King.
+    ///
+    /// The block includes a Knight between the Jack and the Queen, so the Queen and King are at
+    /// `0xD` and `0xE` rather than following on from the Jack. Add this to
+    /// [`Suit::unicode_block_offset`] to get the code point of a card.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Rank;
+    ///
+    /// assert_eq!(Rank::Jack.unicode_card_offset(), 0xB);
+    /// assert_eq!(Rank::Queen.unicode_card_offset(), 0xD);
+    /// ```
+    ///
+    /// [`Suit::unicode_block_offset`]: crate::Suit::unicode_block_offset
+    pub const fn unicode_card_offset(self) -> u32 {
+        match self {
+            Self::Ace => 0x1,
+            Self::Two => 0x2,
+            Self::Three => 0x3,
+            Self::Four => 0x4,
+            Self::Five => 0x5,
+            Self::Six => 0x6,
+            Self::Seven => 0x7,
+            Self::Eight => 0x8,
+            Self::Nine => 0x9,
+            Self::Ten => 0xA,
+            Self::Jack => 0xB,
+            // 0xC is the Knight
+            Self::Queen => 0xD,
+            Self::King => 0xE,
+        }
+    }
+
+    /// Returns the short name of the rank, which is printed in the corners of a card: `A`, `2`
+    /// to `10`, `J`, `Q`, or `K`.
+    ///
+    /// Unlike [`Rank::as_symbol`], which is a single character and writes the Ten as `T`, this
+    /// writes the Ten as `10`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Rank;
+    ///
+    /// assert_eq!(Rank::Ten.short_name(), "10");
+    /// assert_eq!(Rank::Queen.short_name(), "Q");
+    /// ```
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            Self::Ace => "A",
+            Self::Two => "2",
+            Self::Three => "3",
+            Self::Four => "4",
+            Self::Five => "5",
+            Self::Six => "6",
+            Self::Seven => "7",
+            Self::Eight => "8",
+            Self::Nine => "9",
+            Self::Ten => "10",
+            Self::Jack => "J",
+            Self::Queen => "Q",
+            Self::King => "K",
+        }
+    }
+
+    /// Returns the full English name of the rank, e.g. `Ace` or `Seven`, which is also how the rank
+    /// is displayed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Rank;
+    ///
+    /// assert_eq!(Rank::Ten.long_name(), "Ten");
+    /// assert_eq!(Rank::Ten.long_name(), Rank::Ten.to_string());
+    /// ```
+    pub const fn long_name(self) -> &'static str {
+        match self {
+            Self::Ace => "Ace",
+            Self::Two => "Two",
+            Self::Three => "Three",
+            Self::Four => "Four",
+            Self::Five => "Five",
+            Self::Six => "Six",
+            Self::Seven => "Seven",
+            Self::Eight => "Eight",
+            Self::Nine => "Nine",
+            Self::Ten => "Ten",
+            Self::Jack => "Jack",
+            Self::Queen => "Queen",
+            Self::King => "King",
+        }
+    }
+
+    pub const fn as_symbol(self) -> char {
+        match self {
+            Self::Ace => 'A',
+            Self::Two => '2',
+            Self::Three => '3',
+            Self::Four => '4',
+            Self::Five => '5',
+            Self::Six => '6',
+            Self::Seven => '7',
+            Self::Eight => '8',
+            Self::Nine => '9',
+            Self::Ten => 'T',
+            Self::Jack => 'J',
+            Self::Queen => 'Q',
+            Self::King => 'K',
+        }
+    }
+
+    /// Returns the template used to render the rank at the given size.
+    ///
+    /// Each `xx` in the template is replaced by the suit symbol when rendering. [`CardSize::Glyph`]
+    /// cards are rendered as a single Unicode playing card character, and [`CardSize::Tiny`] cards
+    /// as a single line of text, so they have an empty template.
+    pub const fn template(self, size: CardSize) -> &'static str {
+        match size {
+            CardSize::Glyph | CardSize::Tiny => "",
+            CardSize::Compact => self.compact_template(),
+            CardSize::Small => self.small_template(),
+            CardSize::Normal => self.normal_template(),
+            CardSize::Large => self.large_template(),
+        }
+    }
+
+    const fn compact_template(self) -> &'static str {
+        match self {
+            Self::Ace => "Axx     \n────────",
+            Self::Two => "2xx     \n────────",
+            Self::Three => "3xx     \n────────",
+            Self::Four => "4xx     \n────────",
+            Self::Five => "5xx     \n────────",
+            Self::Six => "6xx     \n────────",
+            Self::Seven => "7xx     \n────────",
+            Self::Eight => "8xx     \n────────",
+            Self::Nine => "9xx     \n────────",
+            Self::Ten => "10xx    \n────────",
+            Self::Jack => "Jxx     \n────────",
+            Self::Queen => "Qxx     \n────────",
+            Self::King => "Kxx     \n────────",
+        }
+    }
+
+    const fn small_template(self) -> &'static str {
+        match self {
+            Self::Ace => indoc! {"
+                ╭──────╮
+                │A     │
+                │  xx  │
+                │     A│
+                ╰──────╯"},
+            Self::Two => indoc! {"
+                ╭──────╮
+                │2     │
+                │  xx  │
+                │     2│
+                ╰──────╯"},
+            Self::Three => indoc! {"
+                ╭──────╮
+                │3     │
+                │  xx  │
+                │     3│
+                ╰──────╯"},
+            Self::Four => indoc! {"
+                ╭──────╮
+                │4     │
+                │  xx  │
+                │     4│
+                ╰──────╯"},
+            Self::Five => indoc! {"
+                ╭──────╮
+                │5     │
+                │  xx  │
+                │     5│
+                ╰──────╯"},
+            Self::Six => indoc! {"
+                ╭──────╮
+                │6     │
+                │  xx  │
+                │     6│
+                ╰──────╯"},
+            Self::Seven => indoc! {"
+                ╭──────╮
+                │7     │
+                │  xx  │
+                │     7│
+                ╰──────╯"},
+            Self::Eight => indoc! {"
+                ╭──────╮
+                │8     │
+                │  xx  │
+                │     8│
+                ╰──────╯"},
+            Self::Nine => indoc! {"
+                ╭──────╮
+                │9     │
+                │  xx  │
+                │     9│
+                ╰──────╯"},
+            Self::Ten => indoc! {"
+                ╭──────╮
+                │10    │
+                │  xx  │
+                │    10│
+                ╰──────╯"},
+            Self::Jack => indoc! {"
+                ╭──────╮
+                │J     │
+                │  xx  │
+                │     J│
+                ╰──────╯"},
+            Self::Queen => indoc! {"
+                ╭──────╮
+                │Q     │
+                │  xx  │
+                │     Q│
+                ╰──────╯"},
+            Self::King => indoc! {"
+                ╭──────╮
+                │K     │
+                │  xx  │
+                │     K│
+                ╰──────╯"},
+        }
+    }
+
+    const fn normal_template(self) -> &'static str {
+        match self {
+            Self::Ace => indoc! {"
+                ╭────────────╮
+                │ A          │
+                │            │
+                │            │
+                │     xx     │
+                │            │
+                │            │
+                │          A │
+                ╰────────────╯"},
+            Self::Two => indoc! {"
+                ╭────────────╮
+                │ 2   xx     │
+                │            │
+                │            │
+                │            │
+                │            │
+                │            │
+                │     xx   2 │
+                ╰────────────╯"},
+            Self::Three => indoc! {"
+                ╭────────────╮
+                │ 3   xx     │
+                │            │
+                │            │
+                │     xx     │
+                │            │
+                │            │
+                │     xx   3 │
+                ╰────────────╯"},
+            Self::Four => indoc! {"
+                ╭────────────╮
+                │ 4xx    xx  │
+                │            │
+                │            │
+                │            │
+                │            │
+                │            │
+                │  xx    xx4 │
+                ╰────────────╯"},
+            Self::Five => indoc! {"
+                ╭────────────╮
+                │ 5xx    xx  │
+                │            │
+                │            │
+                │     xx     │
+                │            │
+                │            │
+                │  xx    xx5 │
+                ╰────────────╯"},
+            Self::Six => indoc! {"
+                ╭────────────╮
+                │ 6xx    xx  │
+                │            │
+                │            │
+                │  xx    xx  │
+                │            │
+                │            │
+                │  xx    xx6 │
+                ╰────────────╯"},
+            Self::Seven => indoc! {"
+                ╭────────────╮
+                │ 7xx    xx  │
+                │            │
+                │     xx     │
+                │  xx    xx  │
+                │            │
+                │            │
+                │  xx    xx7 │
+                ╰────────────╯"},
+            Self::Eight => indoc! {"
+                ╭────────────╮
+                │ 8xx    xx  │
+                │            │
+                │     xx     │
+                │  xx    xx  │
+                │     xx     │
+                │            │
+                │  xx    xx8 │
+                ╰────────────╯"},
+            Self::Nine => indoc! {"
+                ╭────────────╮
+                │ 9xx    xx  │
+                │            │
+                │  xx    xx  │
+                │     xx     │
+                │  xx    xx  │
+                │            │
+                │  xx    xx9 │
+                ╰────────────╯
+                "},
+            Self::Ten => indoc! {"
+                ╭────────────╮
+                │10xx    xx  │
+                │     xx     │
+                │  xx    xx  │
+                │            │
+                │  xx    xx  │
+                │     xx     │
+                │  xx    xx10│
+                ╰────────────╯"},
+            Self::Jack => indoc! {"
+                ╭────────────╮
+                │ Jxx        │
+                │       JJ   │
+                │       JJ   │
+                │       JJ   │
+                │  JJ   JJ   │
+                │   JJJJJ    │
+                │        xxJ │
+                ╰────────────╯"},
+            Self::Queen => indoc! {"
+                ╭────────────╮
+                │ Qxx        │
+                │   QQQQQ    │
+                │  QQ   QQ   │
+                │  QQ   QQ   │
+                │  QQ   QQ   │
+                │   QQQQ  Q  │
+                │        xxQ │
+                ╰────────────╯
+            "},
+            Self::King => indoc! {"
+                ╭────────────╮
+                │ Kxx        │
+                │  KK    KK  │
+                │  KK   KK   │
+                │  KK KK     │
+                │  KK   KK   │
+                │  KK    KK  │
+                │        xxK │
+                ╰────────────╯"},
+        }
+    }
+
+    const fn large_template(self) -> &'static str {
+        match self {
+            Self::Ace => indoc! {"
+                ╭──────────────────╮
+                │ Axx              │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │        xx        │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │              xxA │
+                ╰──────────────────╯"},
+            Self::Two => indoc! {"
+                ╭──────────────────╮
+                │ 2xx              │
+                │        xx        │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │        xx        │
+                │              xx2 │
+                ╰──────────────────╯"},
+            Self::Three => indoc! {"
+                ╭──────────────────╮
+                │ 3xx              │
+                │        xx        │
+                │                  │
+                │                  │
+                │                  │
+                │        xx        │
+                │                  │
+                │                  │
+                │                  │
+                │        xx        │
+                │              xx3 │
+                ╰──────────────────╯"},
+            Self::Four => indoc! {"
+                ╭──────────────────╮
+                │ 4xx              │
+                │    xx      xx    │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │                  │
+                │    xx      xx    │
+                │              xx4 │
+                ╰──────────────────╯"},
+            Self::Five => indoc! {"
+                ╭──────────────────╮
+                │ 5xx              │
+                │    xx      xx    │
+                │                  │
+                │                  │
+                │                  │
+                │        xx        │
+                │                  │
+                │                  │
+                │                  │
+                │    xx      xx    │
+                │              xx5 │
+                ╰──────────────────╯"},
+            Self::Six => indoc! {"
+                ╭──────────────────╮
+                │ 6xx              │
+                │    xx      xx    │
+                │                  │
+                │                  │
+                │                  │
+                │    xx      xx    │
+                │                  │
+                │                  │
+                │                  │
+                │    xx      xx    │
+                │              xx6 │
+                ╰──────────────────╯"},
+            Self::Seven => indoc! {"
+                ╭──────────────────╮
+                │ 7xx              │
+                │    xx      xx    │
+                │                  │
+                │        xx        │
+                │                  │
+                │    xx      xx    │
+                │                  │
+                │                  │
+                │                  │
+                │    xx      xx    │
+                │              xx7 │
+                ╰──────────────────╯"},
+            Self::Eight => indoc! {"
+                ╭──────────────────╮
+                │ 8xx              │
+                │    xx      xx    │
+                │                  │
+                │        xx        │
+                │                  │
+                │    xx      xx    │
+                │                  │
+                │        xx        │
+                │                  │
+                │    xx      xx    │
+                │              xx8 │
+                ╰──────────────────╯"},
+            Self::Nine => indoc! {"
+                ╭──────────────────╮
+                │ 9xx              │
+                │    xx      xx    │
+                │                  │
+                │                  │
+                │    xx      xx    │
+                │        xx        │
+                │    xx      xx    │
+                │                  │
+                │                  │
+                │    xx      xx    │
+                │              xx9 │
+                ╰──────────────────╯"},
+            Self::Ten => indoc! {"
+                ╭──────────────────╮
+                │10xx              │
+                │    xx      xx    │
+                │        xx        │
+                │                  │
+                │    xx      xx    │
+                │                  │
+                │    xx      xx    │
+                │                  │
+                │        xx        │
+                │    xx      xx    │
+                │              xx10│
+                ╰──────────────────╯"},
+            Self::Jack => indoc! {"
+                ╭──────────────────╮
+                │ Jxx              │
+                │       JJJJJJJ    │
+                │          JJ      │
+                │          JJ      │
+                │          JJ      │
+                │          JJ      │
+                │          JJ      │
+                │    JJ    JJ      │
+                │    JJ    JJ      │
+                │     JJJJJJ       │
+                │              xxJ │
+                ╰──────────────────╯"},
+            Self::Queen => indoc! {"
+                ╭──────────────────╮
+                │ Qxx              │
+                │      QQQQQQQ     │
+                │     QQ     QQ    │
+                │    QQ       QQ   │
+                │    QQ       QQ   │
+                │    QQ       QQ   │
+                │    QQ   QQ  QQ   │
+                │     QQ   QQQQ    │
+                │      QQQQQQQQ    │
+                │             QQ   │
+                │              xxQ │
+                ╰──────────────────╯"},
+            Self::King => indoc! {"
+                ╭──────────────────╮
+                │ Kxx              │
+                │     KK      KK   │
+                │     KK     KK    │
+                │     KK    KK     │
+                │     KK  KK       │
+                │     KKKK         │
+                │     KK  KK       │
+                │     KK    KK     │
+                │     KK     KK    │
+                │     KK      KK   │
+                │              xxK │
+                ╰──────────────────╯"},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::{Card, Suit};
+
+    #[test]
+    fn ranks_and_suits_count_as_map_keys() {
+        let hand = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+        let mut ranks: HashMap<Rank, usize> = HashMap::new();
+        let mut suits: HashMap<Suit, usize> = HashMap::new();
+        for card in hand {
+            *ranks.entry(card.rank).or_default() += 1;
+            *suits.entry(card.suit).or_default() += 1;
+        }
+        assert_eq!(ranks, HashMap::from([(Rank::Nine, 3), (Rank::Two, 2)]));
+        assert_eq!(
+            suits,
+            HashMap::from([(Suit::Hearts, 3), (Suit::Clubs, 1), (Suit::Spades, 1)])
+        );
+    }
+
+    #[test]
+    fn short_and_long_names() {
+        let short: Vec<_> = Rank::iter().map(Rank::short_name).collect();
+        assert_eq!(
+            short,
+            ["A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K"]
+        );
+        for rank in Rank::iter() {
+            assert_eq!(rank.long_name(), rank.to_string());
+            if rank != Rank::Ten {
+                assert_eq!(rank.short_name(), rank.as_symbol().to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn is_court_matches_is_face() {
+        for rank in Rank::iter() {
+            assert_eq!(rank.is_court(), rank.is_face(), "{rank}");
+        }
+    }
+
+    #[test]
+    fn all_matches_iter() {
+        assert_eq!(Rank::ALL.to_vec(), Rank::iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn groups_partition_ranks_except_ace() {
+        let numbers = Rank::iter().filter(|rank| rank.is_number());
+        assert!(numbers.eq(Rank::ALL_NUMBERS));
+        let faces = Rank::iter().filter(|rank| rank.is_face());
+        assert!(faces.eq(Rank::ALL_FACES));
+        assert_eq!(Rank::ALL_COURTS, Rank::ALL_FACES);
+        let others: Vec<_> = Rank::iter()
+            .filter(|rank| !rank.is_number() && !rank.is_face())
+            .collect();
+        assert_eq!(others, [Rank::Ace]);
+    }
+
+    #[test]
+    fn rotate_cycles_through_every_rank() {
+        let mut rank = Rank::Ace;
+        for expected in Rank::iter().cycle().skip(1).take(Rank::COUNT) {
+            rank = rank.rotate();
+            assert_eq!(rank, expected);
+        }
+        assert_eq!(rank, Rank::Ace);
+    }
+
+    #[test]
+    fn from_value_round_trips() {
+        for order in [AceOrder::Low, AceOrder::High] {
+            for rank in Rank::iter() {
+                assert_eq!(Rank::from_value(rank.value(order), order), Some(rank));
+            }
+        }
+        assert_eq!(Rank::from_value(0, AceOrder::Low), None);
+        assert_eq!(Rank::from_value(14, AceOrder::Low), None);
+        assert_eq!(Rank::from_value(15, AceOrder::High), None);
+    }
+
+    #[test]
+    fn from_poker_order() {
+        let ranks: Vec<_> = (2..=14).filter_map(Rank::from_poker_order).collect();
+        assert_eq!(ranks.len(), 13);
+        assert_eq!(ranks[0], Rank::Two);
+        assert_eq!(ranks[12], Rank::Ace);
+        assert_eq!(Rank::from_poker_order(1), None);
+    }
+
+    #[test]
+    fn derived_ord_is_ace_low() {
+        assert!(Rank::Ace < Rank::Two);
+        assert!(Rank::Queen < Rank::King);
+        for (a, b) in Rank::iter().zip(Rank::iter().skip(1)) {
+            assert_eq!(a.cmp(&b), a.cmp_with(b, AceOrder::Low));
+        }
+    }
+
+    #[test]
+    fn ace_high_and_low_helpers() {
+        for rank in Rank::iter() {
+            assert_eq!(rank.value_ace_high(), rank.value(AceOrder::High));
+            assert_eq!(rank.value_ace_low(), rank.value(AceOrder::Low));
+        }
+        assert_eq!(Rank::Ace.value_ace_high(), 14);
+        assert_eq!(Rank::Ace.value_ace_low(), 1);
+        assert_eq!(Rank::Ace.cmp_ace_high(Rank::King), Ordering::Greater);
+        assert_eq!(Rank::Two.cmp_ace_high(Rank::Two), Ordering::Equal);
+    }
+
+    /// Returns true if the ranks form a straight when sorted in the given order.
+    fn is_straight(ranks: &[Rank], order: AceOrder) -> bool {
+        let mut values: Vec<_> = ranks.iter().map(|rank| rank.value(order)).collect();
+        values.sort_unstable();
+        values.windows(2).all(|pair| pair[0] + 1 == pair[1])
+    }
+
+    #[test]
+    fn wheel_straight_is_ace_low() {
+        let wheel = [Rank::Five, Rank::Ace, Rank::Three, Rank::Two, Rank::Four];
+        assert!(is_straight(&wheel, AceOrder::Low));
+        assert!(!is_straight(&wheel, AceOrder::High));
+    }
+
+    #[test]
+    fn broadway_straight_is_ace_high() {
+        let broadway = [Rank::King, Rank::Ten, Rank::Ace, Rank::Queen, Rank::Jack];
+        assert!(is_straight(&broadway, AceOrder::High));
+        assert!(!is_straight(&broadway, AceOrder::Low));
+        let mut sorted = broadway;
+        sorted.sort_by(|a, b| a.cmp_with(*b, AceOrder::High));
+        assert_eq!(
+            sorted,
+            [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace]
+        );
+    }
+
+    #[test]
+    fn compact_templates_match_dimensions() {
+        let size = CardSize::Compact.dimensions();
+        for rank in Rank::iter() {
+            let template = rank.template(CardSize::Compact);
+            assert_eq!(template.lines().count(), usize::from(size.height), "{rank}");
+            assert!(template.starts_with(rank.short_name()), "{rank}");
+            for line in template.lines() {
+                assert_eq!(line.chars().count(), usize::from(size.width), "{rank}");
+            }
+        }
+    }
+
+    #[test]
+    fn large_templates_have_one_pip_per_rank() {
+        for (pips, rank) in (1..).zip(Rank::ALL.into_iter().take(10)) {
+            let template = rank.template(CardSize::Large);
+            // each corner also shows a suit symbol
+            assert_eq!(template.matches("xx").count(), pips + 2, "{rank}");
+        }
+    }
+
+    #[test]
+    fn large_templates_match_dimensions() {
+        let size = CardSize::Large.dimensions();
+        for rank in Rank::iter() {
+            let template = rank.template(CardSize::Large);
+            assert_eq!(template.lines().count(), usize::from(size.height), "{rank}");
+            for line in template.lines() {
+                assert_eq!(line.chars().count(), usize::from(size.width), "{rank}");
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::widgets::Widget;
+
+use crate::Card;
+
+/// A set of cards placed freely on a table, such as a solitaire layout or a game board.
+///
+/// Each card is placed at a position relative to the top left of the area the scene is rendered
+/// in. Cards are drawn in the order they were placed, so later cards are drawn on top of earlier
+/// ones where they overlap.
+///
+/// Boards are often larger than the area they are shown in. Cards that fall entirely outside the
+/// area are skipped before any of their rendering work is done, so a scene with thousands of
+/// placements only pays for the cards that are visible.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Position;
+/// use tui_cards::{Card, CardScene, Rank, Suit};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let scene = CardScene::new()
+///     .place(Position::new(0, 0), Card::new(Rank::Ace, Suit::Spades))
+///     .place(Position::new(16, 2), Card::new(Rank::King, Suit::Hearts));
+/// frame.render_widget(&scene, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CardScene {
+    /// The cards in the scene and their positions relative to the top left of the area, in the
+    /// order they are drawn.
+    pub placements: Vec<(Position, Card)>,
+}
+
+impl CardScene {
+    /// Creates an empty scene.
+    pub const fn new() -> Self {
+        Self {
+            placements: Vec::new(),
+        }
+    }
+
+    /// Places a card at the given position, on top of the cards placed before it.
+    #[must_use]
+    pub fn place(mut self, position: Position, card: Card) -> Self {
+        self.push(position, card);
+        self
+    }
+
+    /// Places a card at the given position, on top of the cards placed before it.
+    pub fn push(&mut self, position: Position, card: Card) {
+        self.placements.push((position, card));
+    }
+
+    /// Returns the cards that are at least partly visible when the scene is rendered in `area`,
+    /// with the part of the area each one covers, in the order they are drawn.
+    fn visible(&self, area: Rect) -> impl Iterator<Item = (Rect, &Card)> {
+        self.placements.iter().filter_map(move |(position, card)| {
+            let size = card.size.dimensions();
+            let x = area.x.saturating_add(position.x);
+            let y = area.y.saturating_add(position.y);
+            let bounds = Rect::new(x, y, size.width, size.height).intersection(area);
+            (!bounds.is_empty()).then_some((bounds, card))
+        })
+    }
+}
+
+impl Widget for &CardScene {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for (bounds, card) in self.visible(area) {
+            card.render(bounds, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CardSize, Rank, Suit};
+
+    fn card() -> Card {
+        Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small)
+    }
+
+    #[test]
+    fn renders_cards_in_placement_order() {
+        let scene = CardScene::new().place(Position::new(0, 0), card()).place(
+            Position::new(4, 1),
+            Card::new(Rank::Two, Suit::Hearts).size(CardSize::Small),
+        );
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+        scene.render(buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "╭");
+        assert_eq!(buf[(1, 1)].symbol(), "A");
+        // the second card covers the right of the first
+        assert_eq!(buf[(4, 1)].symbol(), "╭");
+        assert_eq!(buf[(5, 2)].symbol(), "2");
+    }
+
+    #[test]
+    fn positions_are_relative_to_the_area() {
+        let scene = CardScene::new().place(Position::new(2, 1), card());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+        scene.render(Rect::new(5, 3, 15, 7), &mut buf);
+        assert_eq!(buf[(7, 4)].symbol(), "╭");
+        assert_eq!(buf[(6, 4)].symbol(), " ");
+    }
+
+    #[test]
+    fn culls_cards_outside_the_area() {
+        let mut scene = CardScene::new();
+        for offset in 0..1000 {
+            scene.push(Position::new(200 + offset * 8, 500), card());
+        }
+        // cards just past the area, where the buffer has room for them
+        scene.push(Position::new(90, 2), card());
+        scene.push(Position::new(2, 30), card());
+        scene.push(Position::new(1, 1), card());
+        let area = Rect::new(0, 0, 80, 24);
+        let visible: Vec<_> = scene.visible(area).collect();
+        assert_eq!(visible, [(Rect::new(1, 1, 8, 5), &card())]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 120, 40));
+        scene.render(area, &mut buf);
+        // only the visible card is drawn, every other cell of the buffer is untouched
+        let mut expected = Buffer::empty(buf.area);
+        card().render(Rect::new(1, 1, 8, 5), &mut expected);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn clips_cards_to_the_area() {
+        let scene = CardScene::new().place(Position::new(6, 0), card());
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+        scene.render(area, &mut buf);
+        assert_eq!(buf[(9, 0)].symbol(), "─");
+        assert_eq!(buf[(10, 0)].symbol(), " ");
+        assert_eq!(buf[(6, 3)].symbol(), " ");
+    }
+}
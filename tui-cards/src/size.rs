@@ -0,0 +1,175 @@
+use ratatui_core::layout::{Rect, Size};
+use strum::EnumIter;
+
+/// The size a [`Card`] is rendered at.
+///
+/// Sizes are declared from smallest to largest.
+///
+/// [`Card`]: crate::Card
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CardSize {
+    /// A single Unicode playing card character, e.g. `🂡`.
+    Glyph,
+    /// A single line with the rank and suit symbol, e.g. `A♠`, for logs, lists, and narrow
+    /// columns.
+    Tiny,
+    /// A two line card with the rank and suit symbol on top of a thin underline, for dense lists
+    /// and status lines.
+    Compact,
+    /// A small boxed card with the rank in two corners and a single suit symbol.
+    Small,
+    /// A full size card with the suit symbols laid out as pips and art for the court cards.
+    #[default]
+    Normal,
+    /// A large card with widely spaced pips and bigger art for the court cards, for terminals with
+    /// room to spare.
+    Large,
+}
+
+impl CardSize {
+    /// Returns the number of columns and rows a card of this size occupies.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::Size;
+    /// use tui_cards::CardSize;
+    ///
+    /// assert_eq!(CardSize::Normal.dimensions(), Size::new(14, 9));
+    /// ```
+    pub const fn dimensions(self) -> Size {
+        match self {
+            Self::Glyph => Size::new(1, 1),
+            Self::Tiny => Size::new(3, 1),
+            Self::Compact => Size::new(8, 2),
+            Self::Small => Size::new(8, 5),
+            Self::Normal => Size::new(14, 9),
+            Self::Large => Size::new(20, 13),
+        }
+    }
+
+    /// Returns the largest size that fits entirely within the given area.
+    ///
+    /// A size fits when both its width and height are less than or equal to the area's, so an area
+    /// exactly the size of a card fits that card. Areas too small for even a [`CardSize::Glyph`]
+    /// (i.e. empty areas) still return `Glyph` as it is the smallest size available.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::Rect;
+    /// use tui_cards::CardSize;
+    ///
+    /// assert_eq!(
+    ///     CardSize::largest_fitting(Rect::new(0, 0, 20, 10)),
+    ///     CardSize::Normal
+    /// );
+    /// assert_eq!(
+    ///     CardSize::largest_fitting(Rect::new(0, 0, 13, 9)),
+    ///     CardSize::Small
+    /// );
+    /// ```
+    pub const fn largest_fitting(area: Rect) -> Self {
+        if Self::Large.fits(area) {
+            Self::Large
+        } else if Self::Normal.fits(area) {
+            Self::Normal
+        } else if Self::Small.fits(area) {
+            Self::Small
+        } else if Self::Compact.fits(area) {
+            Self::Compact
+        } else if Self::Tiny.fits(area) {
+            Self::Tiny
+        } else {
+            Self::Glyph
+        }
+    }
+
+    const fn fits(self, area: Rect) -> bool {
+        let Size { width, height } = self.dimensions();
+        width <= area.width && height <= area.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_fitting_at_exact_boundaries() {
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 14, 9)),
+            CardSize::Normal
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 14, 8)),
+            CardSize::Small
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 13, 9)),
+            CardSize::Small
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 8, 5)),
+            CardSize::Small
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 7, 5)),
+            CardSize::Tiny
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 8, 4)),
+            CardSize::Compact
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 8, 2)),
+            CardSize::Compact
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 8, 1)),
+            CardSize::Tiny
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 3, 1)),
+            CardSize::Tiny
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 2, 1)),
+            CardSize::Glyph
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 1, 1)),
+            CardSize::Glyph
+        );
+    }
+
+    #[test]
+    fn largest_fitting_large() {
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 20, 13)),
+            CardSize::Large
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 19, 13)),
+            CardSize::Normal
+        );
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(0, 0, 20, 12)),
+            CardSize::Normal
+        );
+    }
+
+    #[test]
+    fn largest_fitting_empty_area_is_glyph() {
+        assert_eq!(CardSize::largest_fitting(Rect::ZERO), CardSize::Glyph);
+    }
+
+    #[test]
+    fn largest_fitting_ignores_position() {
+        assert_eq!(
+            CardSize::largest_fitting(Rect::new(100, 50, 14, 9)),
+            CardSize::Normal
+        );
+    }
+}
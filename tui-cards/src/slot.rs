@@ -0,0 +1,218 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Rect, Size};
+use ratatui_core::style::Style;
+use ratatui_core::widgets::Widget;
+use strum::EnumIter;
+
+use crate::card::render_lines;
+use crate::CardSize;
+
+/// A card sized marker for a place on the table that holds no card, such as an empty pile.
+///
+/// A slot is drawn as a dashed outline the size of a card. A [`SlotKind::Burned`] slot is instead
+/// crossed out and dimmed, to show that a card was there but has been removed from play, which
+/// an empty slot cannot tell apart from a pile that was never used.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{CardSize, CardSlot, SlotKind};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let slot = CardSlot::new().size(CardSize::Small).kind(SlotKind::Burned);
+/// frame.render_widget(&slot, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardSlot {
+    /// Whether the slot is empty or marks a removed card. Defaults to [`SlotKind::Empty`].
+    pub kind: SlotKind,
+    /// The size of the card the slot is for. Defaults to [`CardSize::Normal`].
+    pub size: CardSize,
+    /// The style of the marker. Defaults to a dark gray foreground.
+    pub style: Style,
+}
+
+/// What a [`CardSlot`] marks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SlotKind {
+    /// A place that holds no card, drawn as a dashed outline.
+    #[default]
+    Empty,
+    /// A place whose card was burned (removed from play), drawn as a dim crossed out card.
+    Burned,
+}
+
+impl CardSlot {
+    /// Creates an empty slot for a normal size card.
+    pub const fn new() -> Self {
+        Self {
+            kind: SlotKind::Empty,
+            size: CardSize::Normal,
+            style: Style::new().dark_gray(),
+        }
+    }
+
+    /// Sets what the slot marks.
+    #[must_use]
+    pub const fn kind(mut self, kind: SlotKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the size of the card the slot is for.
+    #[must_use]
+    pub const fn size(mut self, size: CardSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the marker.
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the lines of the marker, laid out to fill the size of the slot.
+    fn lines(self) -> Vec<String> {
+        let Size { width, height } = self.size.dimensions();
+        let width = usize::from(width);
+        match (self.kind, self.size) {
+            (SlotKind::Empty, CardSize::Glyph) => vec!["▯".to_string()],
+            (SlotKind::Burned, CardSize::Glyph) => vec!["╳".to_string()],
+            (SlotKind::Empty, CardSize::Tiny) => vec!["╌".repeat(width)],
+            (SlotKind::Burned, CardSize::Tiny) => vec!["╳".repeat(width)],
+            (SlotKind::Empty, CardSize::Compact) => vec![" ".repeat(width), "╌".repeat(width)],
+            (SlotKind::Burned, CardSize::Compact) => vec!["╳".repeat(width), "─".repeat(width)],
+            (SlotKind::Empty, _) => outline(width, usize::from(height), "╌", "╎", |_, _| ' '),
+            (SlotKind::Burned, _) => {
+                let height = usize::from(height);
+                let (inner_width, inner_height) = (width - 2, height - 2);
+                outline(width, height, "─", "│", |row, column| {
+                    // the column each diagonal crosses the middle of the row at
+                    let falling = (2 * row + 1) * inner_width / (2 * inner_height);
+                    let rising = inner_width - 1 - falling;
+                    match column {
+                        column if column == falling && column == rising => '╳',
+                        column if column == falling => '╲',
+                        column if column == rising => '╱',
+                        _ => ' ',
+                    }
+                })
+            }
+        }
+    }
+}
+
+impl Default for CardSlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for &CardSlot {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let style = match self.kind {
+            SlotKind::Empty => self.style,
+            SlotKind::Burned => self.style.dim(),
+        };
+        render_lines(self.lines(), area, buf, style);
+    }
+}
+
+/// Returns the lines of a rounded box drawn with the given edges, with each inner cell given by
+/// `fill(row, column)` counted from the top left inner cell.
+fn outline(
+    width: usize,
+    height: usize,
+    horizontal: &str,
+    vertical: &str,
+    fill: impl Fn(usize, usize) -> char,
+) -> Vec<String> {
+    let inner = width.saturating_sub(2);
+    let last = height.saturating_sub(1);
+    (0..=last)
+        .map(|row| match row {
+            0 => format!("╭{}╮", horizontal.repeat(inner)),
+            row if row == last => format!("╰{}╯", horizontal.repeat(inner)),
+            row => {
+                let inside: String = (0..inner).map(|column| fill(row - 1, column)).collect();
+                format!("{vertical}{inside}{vertical}")
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn render_empty() {
+        let slot = CardSlot::new().size(CardSize::Small);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        slot.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭╌╌╌╌╌╌╮", "╎      ╎", "╎      ╎", "╎      ╎", "╰╌╌╌╌╌╌╯"]);
+        expected.set_style(expected.area, Style::new().dark_gray());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_burned() {
+        let slot = CardSlot::new().size(CardSize::Small).kind(SlotKind::Burned);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        slot.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["╭──────╮", "│ ╲  ╱ │", "│  ╱╲  │", "│╱    ╲│", "╰──────╯"]);
+        expected.set_style(expected.area, Style::new().dark_gray().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_burned_normal() {
+        let slot = CardSlot::new().kind(SlotKind::Burned);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        slot.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "╭────────────╮",
+            "│╲          ╱│",
+            "│  ╲      ╱  │",
+            "│    ╲  ╱    │",
+            "│     ╱╲     │",
+            "│    ╱  ╲    │",
+            "│  ╱      ╲  │",
+            "│╱          ╲│",
+            "╰────────────╯",
+        ]);
+        expected.set_style(expected.area, Style::new().dark_gray().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn lines_fill_the_card_size() {
+        for (kind, size) in SlotKind::iter().cartesian_product(CardSize::iter()) {
+            let slot = CardSlot::new().kind(kind).size(size);
+            let Size { width, height } = size.dimensions();
+            let lines = slot.lines();
+            assert_eq!(lines.len(), usize::from(height), "{kind:?} {size:?}");
+            for line in lines {
+                assert_eq!(
+                    line.chars().count(),
+                    usize::from(width),
+                    "{kind:?} {size:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Rect, Size};
+use ratatui_core::style::Style;
+use ratatui_core::widgets::Widget;
+
+use crate::card::render_lines;
+use crate::{CardSize, CardTheme, Rank, Suit, SymbolPresentation};
+
+/// A card divided into a top and a bottom half, each with its own rank and suit.
+///
+/// This is useful for games with split or double sided cards, or for showing a damaged card. The
+/// card is the size of a [`CardSize::Normal`] card. Each half shows its index next to the suit
+/// symbol and a single pip, and is drawn in its own suit's color, with a horizontal line dividing
+/// the two halves. The index of the bottom half is in the bottom right corner, as on a regular
+/// card.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{Rank, SplitCard, Suit};
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let card = SplitCard::new((Rank::Ace, Suit::Spades), (Rank::Seven, Suit::Hearts));
+/// frame.render_widget(&card, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitCard {
+    /// The rank and suit of the top half.
+    pub top: (Rank, Suit),
+    /// The rank and suit of the bottom half.
+    pub bottom: (Rank, Suit),
+    /// The colors the card is rendered with.
+    pub theme: CardTheme,
+}
+
+impl SplitCard {
+    /// Creates a split card from the rank and suit of each half.
+    pub const fn new(top: (Rank, Suit), bottom: (Rank, Suit)) -> Self {
+        Self {
+            top,
+            bottom,
+            theme: CardTheme::new(),
+        }
+    }
+
+    /// Sets the colors the card is rendered with.
+    #[must_use]
+    pub const fn theme(mut self, theme: CardTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Returns the number of columns and rows the card occupies.
+    pub const fn dimensions(&self) -> Size {
+        CardSize::Normal.dimensions()
+    }
+
+    fn style(&self, suit: Suit) -> Style {
+        Style::new()
+            .fg(self
+                .theme
+                .suits
+                .resolved_for(self.theme.background)
+                .color(suit))
+            .bg(self.theme.background)
+    }
+}
+
+impl Widget for &SplitCard {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (top_rank, top_suit) = self.top;
+        let (bottom_rank, bottom_suit) = self.bottom;
+        let top_symbol = top_suit.template_symbol(SymbolPresentation::FourColor);
+        let bottom_symbol = bottom_suit.template_symbol(SymbolPresentation::FourColor);
+        let top = [
+            "╭────────────╮".to_string(),
+            format!("│{:>2}{top_symbol}        │", top_rank.short_name()),
+            format!("│     {top_symbol}     │"),
+            "│            │".to_string(),
+            "├────────────┤".to_string(),
+        ];
+        let bottom = [
+            "│            │".to_string(),
+            format!("│     {bottom_symbol}     │"),
+            format!("│        {bottom_symbol}{:<2}│", bottom_rank.short_name()),
+            "╰────────────╯".to_string(),
+        ];
+        render_lines(top, area, buf, self.style(top_suit));
+        let bottom_area = Rect {
+            y: area.y.saturating_add(5),
+            height: area.height.saturating_sub(5),
+            ..area
+        };
+        render_lines(bottom, bottom_area, buf, self.style(bottom_suit));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_split_card() {
+        let card = SplitCard::new((Rank::Ten, Suit::Spades), (Rank::Seven, Suit::Hearts));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        card.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "╭────────────╮",
+            "│10♠️        │",
+            "│     ♠️     │",
+            "│            │",
+            "├────────────┤",
+            "│            │",
+            "│     ♥️     │",
+            "│        ♥️7 │",
+            "╰────────────╯",
+        ]);
+        expected.set_style(Rect::new(0, 0, 14, 5), Style::new().black().on_white());
+        expected.set_style(Rect::new(0, 5, 14, 4), Style::new().red().on_white());
+        // the cells hidden by the wide suit symbols are skipped when rendering
+        for position in [(4, 1), (7, 2), (7, 6), (10, 7)] {
+            expected[position].set_style(Style::reset());
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_clipped() {
+        let card = SplitCard::new((Rank::Ace, Suit::Clubs), (Rank::Two, Suit::Clubs));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 3));
+        card.render(Rect::new(0, 0, 14, 9), &mut buf);
+        assert_eq!(buf[(2, 1)].symbol(), "A");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        card.render(Rect::new(0, 0, 14, 6), &mut buf);
+        assert_eq!(buf[(0, 5)].symbol(), "│");
+        assert_eq!(buf[(0, 6)].symbol(), " ");
+    }
+
+    #[test]
+    fn render_at_the_largest_coordinates() {
+        let card = SplitCard::new((Rank::Ten, Suit::Spades), (Rank::Seven, Suit::Hearts));
+        let mut buf = Buffer::empty(Rect::new(u16::MAX - 5, u16::MAX - 5, 5, 5));
+        card.render(buf.area, &mut buf);
+        assert_eq!(buf[(u16::MAX - 5, u16::MAX - 5)].symbol(), "╭");
+    }
+}
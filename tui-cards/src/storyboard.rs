@@ -0,0 +1,181 @@
+use std::fmt;
+use std::time::Duration;
+
+use ratatui_core::terminal::Frame;
+
+/// A sequence of frames played one after another, such as dealing, flipping, and moving cards.
+///
+/// Each frame is a closure that draws to a [`Frame`], paired with how long it should be shown.
+/// The storyboard does not read input or sleep by itself, so the same frames can be played in a
+/// terminal, stepped through in tests, or recorded with a terminal recorder such as [VHS] to
+/// produce an animated demo.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::{Duration, Instant};
+///
+/// use tui_cards::{Card, Facing, Rank, Storyboard, Suit};
+///
+/// let card = Card::new(Rank::Ace, Suit::Spades);
+/// let storyboard = Storyboard::new()
+///     .frame(Duration::from_millis(500), move |frame| {
+///         frame.render_widget(&card.facing(Facing::Down), frame.area());
+///     })
+///     .frame(Duration::from_millis(500), move |frame| {
+///         frame.render_widget(&card, frame.area());
+///     });
+///
+/// let mut terminal = ratatui::init();
+/// let start = Instant::now();
+/// while let Some(scene) = storyboard.frame_at(start.elapsed()) {
+///     terminal.draw(|frame| scene.draw(frame))?;
+///     std::thread::sleep(Duration::from_millis(16));
+/// }
+/// ratatui::restore();
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// [VHS]: https://github.com/charmbracelet/vhs
+#[derive(Default)]
+pub struct Storyboard<'a> {
+    frames: Vec<StoryboardFrame<'a>>,
+}
+
+/// A single frame of a [`Storyboard`].
+pub struct StoryboardFrame<'a> {
+    /// How long the frame is shown before the next one.
+    pub duration: Duration,
+    draw: Box<dyn Fn(&mut Frame) + 'a>,
+}
+
+impl<'a> Storyboard<'a> {
+    /// Creates an empty storyboard.
+    pub fn new() -> Self {
+        Self { frames: Vec::new() }
+    }
+
+    /// Adds a frame that is shown for the given duration after the frames added before it.
+    #[must_use]
+    pub fn frame<F>(mut self, duration: Duration, draw: F) -> Self
+    where
+        F: Fn(&mut Frame) + 'a,
+    {
+        self.push(duration, draw);
+        self
+    }
+
+    /// Adds a frame that is shown for the given duration after the frames added before it.
+    pub fn push<F>(&mut self, duration: Duration, draw: F)
+    where
+        F: Fn(&mut Frame) + 'a,
+    {
+        self.frames.push(StoryboardFrame {
+            duration,
+            draw: Box::new(draw),
+        });
+    }
+
+    /// Returns the frames in the order they are shown.
+    pub fn frames(&self) -> &[StoryboardFrame<'a>] {
+        &self.frames
+    }
+
+    /// Returns the time it takes to show every frame.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.duration).sum()
+    }
+
+    /// Returns the frame shown at the given time since the storyboard started, or `None` once
+    /// every frame has been shown.
+    pub fn frame_at(&self, elapsed: Duration) -> Option<&StoryboardFrame<'a>> {
+        let mut end = Duration::ZERO;
+        self.frames.iter().find(|frame| {
+            end += frame.duration;
+            elapsed < end
+        })
+    }
+}
+
+impl StoryboardFrame<'_> {
+    /// Draws the frame.
+    pub fn draw(&self, frame: &mut Frame) {
+        (self.draw)(frame);
+    }
+}
+
+impl fmt::Debug for Storyboard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Storyboard")
+            .field("frames", &self.frames)
+            .finish()
+    }
+}
+
+impl fmt::Debug for StoryboardFrame<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StoryboardFrame")
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    use super::*;
+    use crate::{Card, CardSize, Facing, Rank, Suit};
+
+    fn storyboard() -> Storyboard<'static> {
+        let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Glyph);
+        Storyboard::new()
+            .frame(Duration::from_secs(1), move |frame| {
+                frame.render_widget(&card.facing(Facing::Down), frame.area());
+            })
+            .frame(Duration::from_secs(2), move |frame| {
+                frame.render_widget(&card, frame.area());
+            })
+    }
+
+    #[test]
+    fn frame_at_follows_durations() {
+        let storyboard = storyboard();
+        assert_eq!(storyboard.duration(), Duration::from_secs(3));
+        let index = |secs: f64| {
+            let frame = storyboard.frame_at(Duration::from_secs_f64(secs))?;
+            storyboard
+                .frames()
+                .iter()
+                .position(|candidate| std::ptr::eq(candidate, frame))
+        };
+        assert_eq!(index(0.0), Some(0));
+        assert_eq!(index(0.999), Some(0));
+        assert_eq!(index(1.0), Some(1));
+        assert_eq!(index(2.5), Some(1));
+        assert_eq!(index(3.0), None);
+    }
+
+    #[test]
+    fn draws_each_frame() {
+        let storyboard = storyboard();
+        let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+        let symbols: Vec<_> = storyboard
+            .frames()
+            .iter()
+            .map(|scene| {
+                let completed = terminal.draw(|frame| scene.draw(frame)).unwrap();
+                completed.buffer[(0, 0)].symbol().to_string()
+            })
+            .collect();
+        assert_eq!(symbols, ["🂠", "🂡"]);
+    }
+
+    #[test]
+    fn empty_storyboard_has_no_frames() {
+        let storyboard = Storyboard::new();
+        assert_eq!(storyboard.duration(), Duration::ZERO);
+        assert!(storyboard.frame_at(Duration::ZERO).is_none());
+    }
+}
@@ -0,0 +1,462 @@
+use std::cmp::Ordering;
+
+use ratatui_core::style::Color;
+use strum::{Display, EnumCount, EnumIter};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// The suit of a playing card.
+///
+/// # Ordering
+///
+/// The derived [`Ord`] follows the declaration order: Spades, Hearts, Diamonds, then Clubs. This
+/// is the order of a new [`Deck`], not a ranking used by any particular game.
+///
+/// # Matching
+///
+/// The enum is `#[non_exhaustive]` so that new suits can be added without breaking downstream
+/// code, which means matches on it must include a wildcard arm. Prefer the methods that already
+/// encode the per suit logic, such as [`Suit::color`], [`Suit::is_red`], and the symbol methods,
+/// over matching on the variants yourself.
+///
+/// ```rust
+/// use tui_cards::Suit;
+///
+/// let trumps = Suit::Hearts;
+/// assert!(trumps.is_red());
+/// assert_eq!(trumps.as_symbol(), '♥');
+/// ```
+///
+/// [`Deck`]: crate::Deck
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display, EnumCount, EnumIter,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Suit {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clubs,
+}
+
+/// The forms a suit symbol can be written in.
+///
+/// Each variant corresponds to one of the suit's symbol methods, e.g. [`Suit::as_text_symbol`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SymbolPresentation {
+    /// The plain symbol returned by [`Suit::as_symbol`].
+    Plain,
+    /// The text presentation returned by [`Suit::as_text_symbol`].
+    Text,
+    /// The emoji presentation returned by [`Suit::as_colored_symbol`].
+    Emoji,
+    /// The four color emoji returned by [`Suit::as_four_color_symbol`], which cards are rendered
+    /// with.
+    #[default]
+    FourColor,
+}
+
+/// The order suits are sorted in, which differs from game to game.
+///
+/// Used by [`Suit::cmp_with`], [`Deck::sort_with`], and [`Hand::grouped_by_suit`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::cmp::Ordering;
+///
+/// use tui_cards::{Suit, SuitOrder};
+///
+/// assert_eq!(
+///     Suit::Clubs.cmp_with(Suit::Spades, SuitOrder::Declaration),
+///     Ordering::Greater
+/// );
+/// assert_eq!(
+///     Suit::Clubs.cmp_with(Suit::Spades, SuitOrder::Bridge),
+///     Ordering::Less
+/// );
+/// ```
+///
+/// [`Deck::sort_with`]: crate::Deck::sort_with
+/// [`Hand::grouped_by_suit`]: crate::Hand::grouped_by_suit
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuitOrder {
+    /// The declaration order of [`Suit`]: Spades, Hearts, Diamonds, then Clubs. This matches the
+    /// derived [`Ord`] and the order of a new [`Deck`].
+    ///
+    /// [`Deck`]: crate::Deck
+    #[default]
+    Declaration,
+    /// The rank of the suits in bridge, from lowest to highest: Clubs, Diamonds, Hearts, then
+    /// Spades.
+    Bridge,
+    /// The suits sorted by their English names: Clubs, Diamonds, Hearts, then Spades.
+    ///
+    /// For the four French suits this happens to match [`SuitOrder::Bridge`].
+    Alphabetical,
+    /// The suits in the given order, e.g. alternating colors so that neighboring groups in a hand
+    /// are easy to tell apart. Suits missing from the array sort after the others.
+    Custom([Suit; Suit::COUNT]),
+}
+
+impl SuitOrder {
+    /// Returns the position of the suit in this order, starting from 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Suit, SuitOrder};
+    ///
+    /// assert_eq!(SuitOrder::Bridge.position(Suit::Clubs), 0);
+    /// assert_eq!(SuitOrder::Bridge.position(Suit::Spades), 3);
+    /// ```
+    pub const fn position(self, suit: Suit) -> u8 {
+        match self {
+            Self::Declaration => suit as u8,
+            Self::Bridge | Self::Alphabetical => match suit {
+                Suit::Clubs => 0,
+                Suit::Diamonds => 1,
+                Suit::Hearts => 2,
+                Suit::Spades => 3,
+            },
+            Self::Custom(suits) => {
+                let mut index = 0;
+                while index < suits.len() {
+                    if suits[index] as u8 == suit as u8 {
+                        return index as u8;
+                    }
+                    index += 1;
+                }
+                Suit::COUNT as u8
+            }
+        }
+    }
+}
+
+impl Suit {
+    /// Returns the color of the suit in the default four color palette.
+    ///
+    /// Cards are rendered with the colors of their [`CardTheme`], which can use other palettes
+    /// such as [`SuitTheme::two_color`].
+    ///
+    /// [`CardTheme`]: crate::CardTheme
+    /// [`SuitTheme::two_color`]: crate::SuitTheme::two_color
+    pub const fn color(self) -> Color {
+        match self {
+            Self::Clubs => Color::Green,
+            Self::Diamonds => Color::Blue,
+            Self::Hearts => Color::Red,
+            Self::Spades => Color::Black,
+        }
+    }
+
+    /// Returns true for the traditionally red suits, Hearts and Diamonds.
+    ///
+    /// This ignores the colors cards are rendered in, so it holds for every palette, including the
+    /// four color one where Diamonds are blue.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Suit;
+    ///
+    /// assert!(Suit::Diamonds.is_red());
+    /// assert!(!Suit::Clubs.is_red());
+    /// ```
+    pub const fn is_red(self) -> bool {
+        matches!(self, Self::Hearts | Self::Diamonds)
+    }
+
+    /// Returns true for the traditionally black suits, Spades and Clubs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Suit;
+    ///
+    /// assert!(Suit::Spades.is_black());
+    /// ```
+    pub const fn is_black(self) -> bool {
+        !self.is_red()
+    }
+
+    /// Returns the next suit, cycling Spades, Hearts, Diamonds, Clubs, and back to Spades.
+    ///
+    /// This is handy for pickers that cycle through the suits on a key press.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Suit;
+    ///
+    /// assert_eq!(Suit::Spades.rotate(), Suit::Hearts);
+    /// assert_eq!(Suit::Clubs.rotate(), Suit::Spades);
+    /// ```
+    #[must_use]
+    pub const fn rotate(self) -> Self {
+        match self {
+            Self::Spades => Self::Hearts,
+            Self::Hearts => Self::Diamonds,
+            Self::Diamonds => Self::Clubs,
+            Self::Clubs => Self::Spades,
+        }
+    }
+
+    /// Returns the code point just before the suit's cards in the Unicode Playing Cards block.
+    ///
+    /// Adding a [`Rank::unicode_card_offset`] gives the code point of that card, and the offset
+    /// itself is the code point of the card back (for Spades) or an unused slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Rank, Suit};
+    ///
+    /// assert_eq!(Suit::Hearts.unicode_block_offset(), 0x1F0B0);
+    /// let code_point = Suit::Hearts.unicode_block_offset() + Rank::Ace.unicode_card_offset();
+    /// assert_eq!(char::from_u32(code_point), Some('🂱'));
+    /// ```
+    ///
+    /// [`Rank::unicode_card_offset`]: crate::Rank::unicode_card_offset
+    pub const fn unicode_block_offset(self) -> u32 {
+        match self {
+            Self::Spades => 0x1F0A0,
+            Self::Hearts => 0x1F0B0,
+            Self::Diamonds => 0x1F0C0,
+            Self::Clubs => 0x1F0D0,
+        }
+    }
+
+    /// Compares two suits according to the given order.
+    pub fn cmp_with(self, other: Self, order: SuitOrder) -> Ordering {
+        order.position(self).cmp(&order.position(other))
+    }
+
+    /// Returns the first letter of the suit's name, for terminals that can't draw the suit
+    /// symbols.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Suit;
+    ///
+    /// assert_eq!(Suit::Hearts.as_letter(), 'H');
+    /// ```
+    pub const fn as_letter(self) -> char {
+        match self {
+            Self::Clubs => 'C',
+            Self::Diamonds => 'D',
+            Self::Hearts => 'H',
+            Self::Spades => 'S',
+        }
+    }
+
+    pub const fn as_symbol(self) -> char {
+        match self {
+            Self::Clubs => '♣',
+            Self::Diamonds => '♦',
+            Self::Hearts => '♥',
+            Self::Spades => '♠',
+        }
+    }
+
+    /// Returns the suit symbol followed by the text presentation selector (U+FE0E).
+    ///
+    /// Terminals render `♥` and `♦` inconsistently: some show them as (red, double width) emoji
+    /// while others show plain text. The text presentation selector asks the terminal to render
+    /// the glyph as a single cell monochrome character, which leaves coloring up to the card's
+    /// style. Prefer this over [`Suit::as_colored_symbol`] when alignment matters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::Suit;
+    ///
+    /// assert_eq!(Suit::Hearts.as_text_symbol(), "\u{2665}\u{FE0E}");
+    /// ```
+    pub const fn as_text_symbol(self) -> &'static str {
+        match self {
+            Self::Clubs => "\u{2663}\u{FE0E}",
+            Self::Diamonds => "\u{2666}\u{FE0E}",
+            Self::Hearts => "\u{2665}\u{FE0E}",
+            Self::Spades => "\u{2660}\u{FE0E}",
+        }
+    }
+
+    pub const fn as_colored_symbol(self) -> &'static str {
+        match self {
+            Self::Clubs => "\u{2663}\u{FE0F}",
+            Self::Diamonds => "\u{2666}\u{FE0F}",
+            Self::Hearts => "\u{2665}\u{FE0F}",
+            Self::Spades => "\u{2660}\u{FE0F}",
+        }
+    }
+
+    /// Returns the number of columns the suit symbol occupies in the given presentation.
+    ///
+    /// The plain and text presentations are one column wide, while the emoji presentations are
+    /// two columns wide. Use this when reserving space for a symbol next to other text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Suit, SymbolPresentation};
+    ///
+    /// assert_eq!(Suit::Hearts.symbol_width(SymbolPresentation::Text), 1);
+    /// assert_eq!(Suit::Hearts.symbol_width(SymbolPresentation::Emoji), 2);
+    /// ```
+    pub fn symbol_width(self, presentation: SymbolPresentation) -> u16 {
+        let width = match presentation {
+            SymbolPresentation::Plain => self.as_symbol().width().unwrap_or_default(),
+            SymbolPresentation::Text => self.as_text_symbol().width(),
+            SymbolPresentation::Emoji => self.as_colored_symbol().width(),
+            SymbolPresentation::FourColor => self.as_four_color_symbol().width(),
+        };
+        width as u16
+    }
+
+    /// Returns the suit symbol in the given presentation, padded with spaces to the two columns
+    /// reserved for each symbol (`xx`) in the card templates.
+    ///
+    /// The width is measured rather than assumed, so a symbol narrower than two columns is padded
+    /// and a symbol wider than two columns falls back to the plain symbol, keeping the rest of the
+    /// line, and the card's right border, in place.
+    pub(crate) fn template_symbol(self, presentation: SymbolPresentation) -> String {
+        let presentation = if self.symbol_width(presentation) > 2 {
+            SymbolPresentation::Plain
+        } else {
+            presentation
+        };
+        let padding = 2u16.saturating_sub(self.symbol_width(presentation));
+        self.presentation_symbol(presentation) + &" ".repeat(usize::from(padding))
+    }
+
+    /// Returns the suit symbol in the given presentation.
+    pub(crate) fn presentation_symbol(self, presentation: SymbolPresentation) -> String {
+        match presentation {
+            SymbolPresentation::Plain => self.as_symbol().to_string(),
+            SymbolPresentation::Text => self.as_text_symbol().to_string(),
+            SymbolPresentation::Emoji => self.as_colored_symbol().to_string(),
+            SymbolPresentation::FourColor => self.as_four_color_symbol().to_string(),
+        }
+    }
+
+    pub const fn as_four_color_symbol(self) -> &'static str {
+        match self {
+            Self::Clubs => "\u{2618}\u{FE0F}",     // shamrock
+            Self::Diamonds => "\u{1F537}\u{FE0F}", // blue diamond
+            Self::Hearts => "\u{2665}\u{FE0F}",
+            Self::Spades => "\u{2660}\u{FE0F}",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    fn sorted(order: SuitOrder) -> Vec<Suit> {
+        let mut suits: Vec<_> = Suit::iter().collect();
+        suits.sort_by(|a, b| a.cmp_with(*b, order));
+        suits
+    }
+
+    #[test]
+    fn declaration_order_matches_ord() {
+        assert_eq!(
+            sorted(SuitOrder::Declaration),
+            [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs]
+        );
+        for (a, b) in Suit::iter().zip(Suit::iter().rev()) {
+            assert_eq!(a.cmp(&b), a.cmp_with(b, SuitOrder::Declaration));
+        }
+    }
+
+    #[test]
+    fn bridge_order() {
+        assert_eq!(
+            sorted(SuitOrder::Bridge),
+            [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
+        );
+    }
+
+    #[test]
+    fn alphabetical_order() {
+        let mut by_name: Vec<_> = Suit::iter().collect();
+        by_name.sort_by_key(ToString::to_string);
+        assert_eq!(sorted(SuitOrder::Alphabetical), by_name);
+    }
+
+    #[test]
+    fn custom_order() {
+        let alternating = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
+        assert_eq!(sorted(SuitOrder::Custom(alternating)), alternating);
+        let missing_clubs = SuitOrder::Custom([Suit::Hearts; Suit::COUNT]);
+        assert_eq!(missing_clubs.position(Suit::Hearts), 0);
+        assert_eq!(missing_clubs.position(Suit::Clubs), 4);
+    }
+
+    #[test]
+    fn every_suit_is_red_or_black() {
+        let red: Vec<_> = Suit::iter().filter(|suit| suit.is_red()).collect();
+        let black: Vec<_> = Suit::iter().filter(|suit| suit.is_black()).collect();
+        assert_eq!(red, [Suit::Hearts, Suit::Diamonds]);
+        assert_eq!(black, [Suit::Spades, Suit::Clubs]);
+    }
+
+    #[test]
+    fn text_symbol_starts_with_plain_symbol() {
+        for suit in Suit::iter() {
+            let mut chars = suit.as_text_symbol().chars();
+            assert_eq!(chars.next(), Some(suit.as_symbol()));
+            assert_eq!(chars.next(), Some('\u{FE0E}'));
+            assert_eq!(chars.next(), None);
+        }
+    }
+
+    #[test]
+    fn rotate_cycles_through_every_suit() {
+        let mut suit = Suit::Spades;
+        for expected in Suit::iter().cycle().skip(1).take(Suit::COUNT) {
+            suit = suit.rotate();
+            assert_eq!(suit, expected);
+        }
+        assert_eq!(suit, Suit::Spades);
+    }
+
+    #[test]
+    fn template_symbols_fill_two_columns() {
+        for suit in Suit::iter() {
+            for presentation in SymbolPresentation::iter() {
+                assert_eq!(suit.template_symbol(presentation).width(), 2, "{suit}");
+            }
+        }
+        assert_eq!(
+            Suit::Spades.template_symbol(SymbolPresentation::Plain),
+            "♠ "
+        );
+    }
+
+    #[test]
+    fn unicode_block_offsets() {
+        let offsets: Vec<_> = Suit::iter().map(Suit::unicode_block_offset).collect();
+        assert_eq!(offsets, [0x1F0A0, 0x1F0B0, 0x1F0C0, 0x1F0D0]);
+    }
+
+    #[test]
+    fn symbol_widths() {
+        for suit in Suit::iter() {
+            assert_eq!(suit.symbol_width(SymbolPresentation::Plain), 1, "{suit}");
+            assert_eq!(suit.symbol_width(SymbolPresentation::Text), 1, "{suit}");
+            assert_eq!(suit.symbol_width(SymbolPresentation::Emoji), 2, "{suit}");
+            assert_eq!(
+                suit.symbol_width(SymbolPresentation::FourColor),
+                2,
+                "{suit}"
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Utilities for writing golden tests of rendered cards.
+//!
+//! [`assert_card_lines`] renders a card and compares it to the expected art. When they differ the
+//! panic message shows the expected and actual art side by side, with the differing cells marked,
+//! which is far easier to read than the debug output of two buffers.
+//!
+//! Only the symbols of the cells are compared; styles are ignored.
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::widgets::Widget;
+use unicode_width::UnicodeWidthStr;
+
+use crate::Card;
+
+/// Returns the symbols of each row of the buffer as a string.
+///
+/// Cells hidden behind a wide character are skipped, so the lines match the strings passed to
+/// [`Buffer::with_lines`].
+pub fn buffer_lines(buf: &Buffer) -> Vec<String> {
+    let area = buf.area;
+    area.rows()
+        .map(|row| {
+            let mut line = String::new();
+            let mut x = row.x;
+            while x < row.right() {
+                let symbol = buf[(x, row.y)].symbol();
+                line.push_str(symbol);
+                x = x.saturating_add(symbol.width().max(1) as u16);
+            }
+            line
+        })
+        .collect()
+}
+
+/// Renders the card into a buffer the size of the card and returns its lines.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::testing::card_lines;
+/// use tui_cards::{Card, CardSize, Rank, Suit};
+///
+/// let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Small);
+/// assert_eq!(card_lines(&card)[1], "│A     │");
+/// ```
+pub fn card_lines(card: &Card) -> Vec<String> {
+    let size = card.size.dimensions();
+    let mut buf = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+    card.render(buf.area, &mut buf);
+    buffer_lines(&buf)
+}
+
+/// Compares the symbols of two buffers and describes the differences.
+///
+/// Returns `None` when the buffers have the same area and symbols. Otherwise returns the expected
+/// and actual lines side by side, with a line of `^` under each row marking the cells that differ.
+pub fn diff(expected: &Buffer, actual: &Buffer) -> Option<String> {
+    let area = expected.area.union(actual.area);
+    let differs = |x: u16, y: u16| {
+        let symbol = |buf: &Buffer| buf.cell((x, y)).map(|cell| cell.symbol().to_string());
+        symbol(expected) != symbol(actual)
+    };
+    if expected.area == actual.area && area.positions().all(|p| !differs(p.x, p.y)) {
+        return None;
+    }
+    let expected_lines = buffer_lines(expected);
+    let actual_lines = buffer_lines(actual);
+    let width = usize::from(expected.area.width).max("expected".len());
+    let mut output = format!("{:<width$} │ actual\n", "expected");
+    for (index, row) in (0..).zip(area.rows()) {
+        let expected_line = expected_lines.get(index).map_or("", String::as_str);
+        let actual_line = actual_lines.get(index).map_or("", String::as_str);
+        let padding = width.saturating_sub(expected_line.width());
+        output.push_str(&format!(
+            "{expected_line}{} │ {actual_line}\n",
+            " ".repeat(padding)
+        ));
+        let markers: String = (row.x..row.right())
+            .map(|x| if differs(x, row.y) { '^' } else { ' ' })
+            .collect();
+        if markers.contains('^') {
+            let offset = usize::from(actual.area.x.saturating_sub(area.x));
+            let markers = markers.get(offset..).unwrap_or_default().trim_end();
+            output.push_str(&format!("{} │ {markers}\n", " ".repeat(width)));
+        }
+    }
+    Some(output)
+}
+
+/// Asserts that the card renders as the expected lines, panicking with a side by side [`diff`]
+/// if it does not.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::testing::assert_card_lines;
+/// use tui_cards::{Card, CardSize, Rank, Suit};
+///
+/// let card = Card::new(Rank::Ten, Suit::Spades).size(CardSize::Small);
+/// assert_card_lines(
+///     &card,
+///     ["╭──────╮", "│10    │", "│  ♠️  │", "│    10│", "╰──────╯"],
+/// );
+/// ```
+#[track_caller]
+pub fn assert_card_lines<'a, I>(card: &Card, expected: I)
+where
+    I: IntoIterator,
+    I::Item: Into<ratatui_core::text::Line<'a>>,
+{
+    let size = card.size.dimensions();
+    let mut actual = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+    card.render(actual.area, &mut actual);
+    let expected = Buffer::with_lines(expected);
+    if let Some(diff) = diff(&expected, &actual) {
+        panic!("{} does not render as expected:\n{diff}", card.describe());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CardSize, Rank, Suit};
+
+    #[test]
+    fn buffer_lines_skip_hidden_cells() {
+        let buf = Buffer::with_lines(["a♠️b", "cdef"]);
+        assert_eq!(buffer_lines(&buf), ["a♠️b", "cdef"]);
+    }
+
+    #[test]
+    fn diff_of_equal_buffers_is_none() {
+        let buf = Buffer::with_lines(["ab", "cd"]);
+        assert_eq!(diff(&buf, &buf.clone()), None);
+    }
+
+    #[test]
+    fn diff_marks_differing_cells() {
+        let expected = Buffer::with_lines(["abc", "def"]);
+        let actual = Buffer::with_lines(["abc", "dxf"]);
+        assert_eq!(
+            diff(&expected, &actual).as_deref(),
+            Some(indoc::indoc! {"
+                expected │ actual
+                abc      │ abc
+                def      │ dxf
+                         │  ^
+            "})
+        );
+    }
+
+    #[test]
+    fn assert_card_lines_passes() {
+        let card = Card::new(Rank::Two, Suit::Hearts).size(CardSize::Small);
+        assert_card_lines(
+            &card,
+            ["╭──────╮", "│2     │", "│  ♥️  │", "│     2│", "╰──────╯"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Two of Hearts does not render as expected")]
+    fn assert_card_lines_panics_with_diff() {
+        let card = Card::new(Rank::Two, Suit::Hearts).size(CardSize::Small);
+        assert_card_lines(
+            &card,
+            ["╭──────╮", "│3     │", "│  ♥️  │", "│     3│", "╰──────╯"],
+        );
+    }
+}
@@ -0,0 +1,818 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Style};
+use ratatui_core::terminal::Frame;
+use ratatui_core::widgets::Widget;
+
+use crate::facing::BACK_COLOR;
+use crate::{Card, Suit, SymbolPresentation};
+
+/// The colors used to draw each suit.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use tui_cards::{Suit, SuitTheme};
+///
+/// let theme = SuitTheme::two_color();
+/// assert_eq!(theme.color(Suit::Diamonds), Color::Red);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SuitTheme {
+    pub spades: Color,
+    pub hearts: Color,
+    pub diamonds: Color,
+    pub clubs: Color,
+}
+
+/// The colors used to render a [`Card`].
+///
+/// The default draws the suits using [`SuitTheme::four_color`] on a white background.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use tui_cards::{Card, CardTheme, Rank, Suit, SuitTheme};
+///
+/// let theme = CardTheme::new()
+///     .suits(SuitTheme::two_color())
+///     .background(Color::Gray);
+/// let card = Card::new(Rank::Ace, Suit::Spades).theme(theme);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardTheme {
+    /// The colors of the suit symbols, indices, and art.
+    pub suits: SuitTheme,
+    /// The background color of the card.
+    pub background: Color,
+    /// The background color of the card's border, or `None` to use [`CardTheme::background`].
+    ///
+    /// Setting this to a different color than the background gives the card a matted look.
+    pub border_background: Option<Color>,
+    /// How the single pip of an Ace is drawn.
+    pub ace_pip: AcePip,
+    /// How wide the pips of [`CardSize::Large`] cards are drawn.
+    ///
+    /// [`CardSize::Large`]: crate::CardSize::Large
+    pub pip_scale: PipScale,
+    /// The form of the suit symbols beside the corner indices. Defaults to
+    /// [`SymbolPresentation::FourColor`].
+    pub corner_suit_symbol: SymbolPresentation,
+    /// The form of the suit symbols in the middle of the card. Defaults to
+    /// [`SymbolPresentation::FourColor`].
+    pub pip_suit_symbol: SymbolPresentation,
+    /// The style of the backs of face down cards, on top of the card's background. Defaults to a
+    /// blue foreground.
+    pub back_style: Style,
+}
+
+/// How the single pip in the middle of an Ace is drawn.
+///
+/// Real cards show one large suit symbol on the Ace rather than a regular pip. Terminals cannot
+/// enlarge a character, so [`AcePip::Large`] draws the suit with block characters instead. This
+/// applies to [`CardSize::Normal`] and [`CardSize::Large`] cards; smaller sizes always use the
+/// regular suit symbol.
+///
+/// [`CardSize::Normal`]: crate::CardSize::Normal
+/// [`CardSize::Large`]: crate::CardSize::Large
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AcePip {
+    /// The same suit symbol as the pips of the other ranks.
+    #[default]
+    Standard,
+    /// A large suit symbol drawn with block characters, filling the middle of the card.
+    Large,
+}
+
+/// How wide the pips in the middle of a [`CardSize::Large`] card are drawn.
+///
+/// Large cards have room to emphasize their pips by drawing each one twice, side by side. The
+/// other sizes are too narrow and always use single pips.
+///
+/// [`CardSize::Large`]: crate::CardSize::Large
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PipScale {
+    /// Each pip is a single suit symbol.
+    #[default]
+    Single,
+    /// Each pip is two suit symbols side by side, centered where the single symbol would be.
+    Double,
+}
+
+impl SuitTheme {
+    /// The four color palette, which gives each suit its own color so they are easy to tell
+    /// apart at a glance. These are the colors returned by [`Suit::color`].
+    pub const fn four_color() -> Self {
+        Self {
+            spades: Suit::Spades.color(),
+            hearts: Suit::Hearts.color(),
+            diamonds: Suit::Diamonds.color(),
+            clubs: Suit::Clubs.color(),
+        }
+    }
+
+    /// The traditional palette with black spades and clubs and red hearts and diamonds.
+    pub const fn two_color() -> Self {
+        Self {
+            spades: Color::Black,
+            hearts: Color::Red,
+            diamonds: Color::Red,
+            clubs: Color::Black,
+        }
+    }
+
+    /// A palette for color vision deficiencies, using colors from the Okabe-Ito palette.
+    ///
+    /// The default palette relies on telling red Hearts from green Clubs, which is hard with the
+    /// most common forms of color blindness. The Okabe-Ito palette (Okabe and Ito, "Color
+    /// Universal Design", 2008) was designed so its colors remain distinguishable with protanopia,
+    /// deuteranopia, and tritanopia. The colors are:
+    ///
+    /// | Suit     | Color          | RGB       |
+    /// | -------- | -------------- | --------- |
+    /// | Spades   | black          | `#000000` |
+    /// | Hearts   | vermillion     | `#D55E00` |
+    /// | Diamonds | blue           | `#0072B2` |
+    /// | Clubs    | reddish purple | `#CC79A7` |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardTheme, Rank, Suit, SuitTheme};
+    ///
+    /// let theme = CardTheme::new().suits(SuitTheme::colorblind_friendly());
+    /// let card = Card::new(Rank::Ace, Suit::Hearts).theme(theme);
+    /// ```
+    pub const fn colorblind_friendly() -> Self {
+        Self {
+            spades: Color::Rgb(0x00, 0x00, 0x00),
+            hearts: Color::Rgb(0xD5, 0x5E, 0x00),
+            diamonds: Color::Rgb(0x00, 0x72, 0xB2),
+            clubs: Color::Rgb(0xCC, 0x79, 0xA7),
+        }
+    }
+
+    /// Returns the color used for the given suit.
+    pub const fn color(self, suit: Suit) -> Color {
+        match suit {
+            Suit::Spades => self.spades,
+            Suit::Hearts => self.hearts,
+            Suit::Diamonds => self.diamonds,
+            Suit::Clubs => self.clubs,
+        }
+    }
+
+    /// Returns the theme with any suit color that is hard to read on the given background replaced
+    /// by a lighter or darker one, e.g. black Spades on a black background become white.
+    ///
+    /// Cards resolve their suit colors against their background when they are rendered, so a
+    /// single theme stays legible on light and dark cards alike. A color is replaced when its
+    /// [contrast ratio] with the background is below 2:1, which only catches colors that are
+    /// nearly invisible, so the standard palettes on the default white background are unchanged.
+    /// A named color is replaced by its light or dark counterpart, e.g. [`Color::Red`] by
+    /// [`Color::LightRed`] on a dark background, and otherwise, or if the counterpart is no
+    /// better, by white or black. [`Color::Reset`] depends on the terminal, so neither it nor
+    /// colors on a `Reset` background are changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_cards::{Suit, SuitTheme};
+    ///
+    /// let theme = SuitTheme::two_color().resolved_for(Color::Black);
+    /// assert_eq!(theme.color(Suit::Spades), Color::White);
+    /// assert_eq!(theme.color(Suit::Hearts), Color::Red);
+    /// ```
+    ///
+    /// [contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    #[must_use]
+    pub fn resolved_for(self, background: Color) -> Self {
+        let resolve = |color| resolve_contrast(color, background);
+        Self {
+            spades: resolve(self.spades),
+            hearts: resolve(self.hearts),
+            diamonds: resolve(self.diamonds),
+            clubs: resolve(self.clubs),
+        }
+    }
+}
+
+/// The lowest contrast ratio between a suit color and the background that is left unchanged by
+/// [`SuitTheme::resolved_for`].
+const MIN_CONTRAST: f64 = 2.0;
+
+/// Returns a color that is legible on the background: the color itself if it contrasts enough,
+/// otherwise its light or dark counterpart, or failing that white or black.
+fn resolve_contrast(color: Color, background: Color) -> Color {
+    let (Some(ratio), Some(background_luminance)) =
+        (contrast(color, background), luminance(background))
+    else {
+        return color;
+    };
+    if ratio >= MIN_CONTRAST {
+        return color;
+    }
+    // the luminance at which white and black have the same contrast with the background
+    let dark_background = background_luminance < 0.179;
+    let counterpart = counterpart(color, dark_background);
+    if contrast(counterpart, background).is_some_and(|ratio| ratio >= MIN_CONTRAST) {
+        counterpart
+    } else if dark_background {
+        Color::White
+    } else {
+        Color::Black
+    }
+}
+
+/// Returns the light counterpart of a dark named color for a dark background, or the dark
+/// counterpart of a light one for a light background, or the color itself if it has none.
+fn counterpart(color: Color, dark_background: bool) -> Color {
+    const PAIRS: [(Color, Color); 9] = [
+        (Color::Black, Color::White),
+        (Color::DarkGray, Color::Gray),
+        (Color::Red, Color::LightRed),
+        (Color::Green, Color::LightGreen),
+        (Color::Yellow, Color::LightYellow),
+        (Color::Blue, Color::LightBlue),
+        (Color::Magenta, Color::LightMagenta),
+        (Color::Cyan, Color::LightCyan),
+        (Color::Gray, Color::White),
+    ];
+    PAIRS
+        .into_iter()
+        .find_map(|(dark, light)| match dark_background {
+            true if color == dark => Some(light),
+            false if color == light => Some(dark),
+            _ => None,
+        })
+        .unwrap_or(color)
+}
+
+/// Returns the [contrast ratio] of two colors, from 1 for identical luminance to 21 for black on
+/// white, or `None` if either is [`Color::Reset`].
+///
+/// [contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+fn contrast(a: Color, b: Color) -> Option<f64> {
+    let (a, b) = (luminance(a)?, luminance(b)?);
+    Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+}
+
+/// Returns the relative luminance of a color, using the xterm defaults for the named and indexed
+/// colors, or `None` for [`Color::Reset`].
+fn luminance(color: Color) -> Option<f64> {
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => indexed_rgb(index),
+        named => indexed_rgb(color_index(named)?),
+    };
+    let channel = |value: u8| {
+        let value = f64::from(value) / 255.0;
+        if value <= 0.040_45 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Some(0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b))
+}
+
+/// Returns the index of a named color in the 16 color palette.
+const fn color_index(color: Color) -> Option<u8> {
+    Some(match color {
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        _ => return None,
+    })
+}
+
+/// Returns the xterm default RGB value of a 256 color palette index.
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0x00, 0x00, 0x00),
+        (0xCD, 0x00, 0x00),
+        (0x00, 0xCD, 0x00),
+        (0xCD, 0xCD, 0x00),
+        (0x00, 0x00, 0xEE),
+        (0xCD, 0x00, 0xCD),
+        (0x00, 0xCD, 0xCD),
+        (0xE5, 0xE5, 0xE5),
+        (0x7F, 0x7F, 0x7F),
+        (0xFF, 0x00, 0x00),
+        (0x00, 0xFF, 0x00),
+        (0xFF, 0xFF, 0x00),
+        (0x5C, 0x5C, 0xFF),
+        (0xFF, 0x00, 0xFF),
+        (0x00, 0xFF, 0xFF),
+        (0xFF, 0xFF, 0xFF),
+    ];
+    match index {
+        0..=15 => BASIC[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Returns a plain name for a color, such as "red" or "light blue", for describing cards in words.
+///
+/// Named colors keep their own name. RGB and indexed colors are named after the closest hue, or
+/// after their lightness when they are nearly gray, so a custom palette is still described as
+/// "orange" rather than by its value.
+pub(crate) fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::Reset => "default",
+        Color::Black => "black",
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Yellow => "yellow",
+        Color::Blue => "blue",
+        Color::Magenta => "magenta",
+        Color::Cyan => "cyan",
+        Color::Gray => "gray",
+        Color::DarkGray => "dark gray",
+        Color::LightRed => "light red",
+        Color::LightGreen => "light green",
+        Color::LightYellow => "light yellow",
+        Color::LightBlue => "light blue",
+        Color::LightMagenta => "light magenta",
+        Color::LightCyan => "light cyan",
+        Color::White => "white",
+        Color::Rgb(r, g, b) => rgb_name(r, g, b),
+        Color::Indexed(index) => {
+            let (r, g, b) = indexed_rgb(index);
+            rgb_name(r, g, b)
+        }
+    }
+}
+
+/// Returns the name of the hue of an RGB color, or of its lightness if it is nearly gray.
+fn rgb_name(r: u8, g: u8, b: u8) -> &'static str {
+    let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+    if max - min < 0x30 {
+        return match (u16::from(max) + u16::from(min)) / 2 {
+            0..0x40 => "black",
+            0x40..0xA0 => "dark gray",
+            0xA0..0xE0 => "gray",
+            _ => "white",
+        };
+    }
+    let chroma = f64::from(max - min);
+    let (red, green, blue) = (f64::from(r), f64::from(g), f64::from(b));
+    let sector = if max == r {
+        ((green - blue) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (blue - red) / chroma + 2.0
+    } else {
+        (red - green) / chroma + 4.0
+    };
+    match sector * 60.0 {
+        hue if hue < 15.0 => "red",
+        hue if hue < 45.0 => "orange",
+        hue if hue < 70.0 => "yellow",
+        hue if hue < 165.0 => "green",
+        hue if hue < 195.0 => "cyan",
+        hue if hue < 260.0 => "blue",
+        hue if hue < 330.0 => "magenta",
+        _ => "red",
+    }
+}
+
+impl Default for SuitTheme {
+    fn default() -> Self {
+        Self::four_color()
+    }
+}
+
+impl CardTheme {
+    /// Creates the default theme.
+    pub const fn new() -> Self {
+        Self {
+            suits: SuitTheme::four_color(),
+            background: Color::White,
+            border_background: None,
+            ace_pip: AcePip::Standard,
+            pip_scale: PipScale::Single,
+            corner_suit_symbol: SymbolPresentation::FourColor,
+            pip_suit_symbol: SymbolPresentation::FourColor,
+            back_style: Style::new().fg(BACK_COLOR),
+        }
+    }
+
+    /// Sets the colors used for each suit.
+    #[must_use]
+    pub const fn suits(mut self, suits: SuitTheme) -> Self {
+        self.suits = suits;
+        self
+    }
+
+    /// Sets the background color of the card.
+    #[must_use]
+    pub const fn background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Sets the background color of the card's border, which otherwise uses the background color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_cards::CardTheme;
+    ///
+    /// let matted = CardTheme::new()
+    ///     .background(Color::White)
+    ///     .border_background(Color::Gray);
+    /// ```
+    #[must_use]
+    pub const fn border_background(mut self, color: Color) -> Self {
+        self.border_background = Some(color);
+        self
+    }
+
+    /// Sets how the single pip of an Ace is drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{AcePip, CardTheme};
+    ///
+    /// let theme = CardTheme::new().ace_pip(AcePip::Large);
+    /// ```
+    #[must_use]
+    pub const fn ace_pip(mut self, ace_pip: AcePip) -> Self {
+        self.ace_pip = ace_pip;
+        self
+    }
+
+    /// Sets how wide the pips of [`CardSize::Large`] cards are drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{Card, CardSize, CardTheme, PipScale, Rank, Suit};
+    ///
+    /// let theme = CardTheme::new().pip_scale(PipScale::Double);
+    /// let card = Card::new(Rank::Seven, Suit::Hearts)
+    ///     .size(CardSize::Large)
+    ///     .theme(theme);
+    /// ```
+    ///
+    /// [`CardSize::Large`]: crate::CardSize::Large
+    #[must_use]
+    pub const fn pip_scale(mut self, pip_scale: PipScale) -> Self {
+        self.pip_scale = pip_scale;
+        self
+    }
+
+    /// Sets the form of the suit symbols beside the corner indices.
+    ///
+    /// Some decks print a smaller or plainer mark in the corners than in the middle of the card.
+    /// The corner symbols are the ones drawn directly beside or under the rank, so number cards of
+    /// [`CardSize::Normal`], whose corner symbols are part of the pip layout, only have them with
+    /// [`CornerLayout::Stacked`]. Symbols one column wide are followed by a space so the layout is
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardTheme, SymbolPresentation};
+    ///
+    /// let theme = CardTheme::new().corner_suit_symbol(SymbolPresentation::Text);
+    /// ```
+    ///
+    /// [`CardSize::Normal`]: crate::CardSize::Normal
+    /// [`CornerLayout::Stacked`]: crate::CornerLayout::Stacked
+    #[must_use]
+    pub const fn corner_suit_symbol(mut self, presentation: SymbolPresentation) -> Self {
+        self.corner_suit_symbol = presentation;
+        self
+    }
+
+    /// Sets the form of the suit symbols in the middle of the card, i.e. every symbol that is not
+    /// beside a corner index. See [`CardTheme::corner_suit_symbol`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_cards::{CardTheme, SymbolPresentation};
+    ///
+    /// let theme = CardTheme::new().pip_suit_symbol(SymbolPresentation::Plain);
+    /// ```
+    #[must_use]
+    pub const fn pip_suit_symbol(mut self, presentation: SymbolPresentation) -> Self {
+        self.pip_suit_symbol = presentation;
+        self
+    }
+
+    /// Sets the style of the backs of face down cards.
+    ///
+    /// The style is applied on top of the card's background, and is the same whatever the suit of
+    /// the hidden card, so every back in a deck looks identical and gives nothing away. Cards with
+    /// [`Card::back_tinted`] set still draw their back in their suit's color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use tui_cards::{Card, CardTheme, Facing, Rank, Suit};
+    ///
+    /// let theme = CardTheme::new().back_style(Style::new().red().on_black());
+    /// let card = Card::new(Rank::Ace, Suit::Spades)
+    ///     .theme(theme)
+    ///     .facing(Facing::Down);
+    /// ```
+    #[must_use]
+    pub fn back_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.back_style = style.into();
+        self
+    }
+}
+
+impl Default for CardTheme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An extension trait for rendering a card with a theme in a single call.
+///
+/// The theme is applied to a copy of the card, so the card itself is left unchanged. This is
+/// implemented for [`Buffer`] and [`Frame`].
+///
+/// # Example
+///
+/// ```rust
+/// use tui_cards::{Card, CardRenderExt, CardTheme, Rank, Suit, SuitTheme};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let card = Card::new(Rank::Ace, Suit::Hearts);
+/// let theme = CardTheme::new().suits(SuitTheme::two_color());
+/// frame.render_card(&card, frame.area(), theme);
+/// # }
+/// ```
+///
+/// [`Frame`]: ratatui_core::terminal::Frame
+pub trait CardRenderExt {
+    /// Renders the card into the given area using the given theme.
+    fn render_card(&mut self, card: &Card, area: Rect, theme: CardTheme);
+}
+
+impl CardRenderExt for Buffer {
+    fn render_card(&mut self, card: &Card, area: Rect, theme: CardTheme) {
+        card.theme(theme).render(area, self);
+    }
+}
+
+impl CardRenderExt for Frame<'_> {
+    fn render_card(&mut self, card: &Card, area: Rect, theme: CardTheme) {
+        self.render_widget(&card.theme(theme), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use ratatui_core::style::Style;
+    use strum::{EnumCount, IntoEnumIterator};
+
+    use super::*;
+    use crate::{CardSize, Rank};
+
+    #[test]
+    fn colorblind_friendly_colors_are_distinct() {
+        let theme = SuitTheme::colorblind_friendly();
+        let colors: HashSet<_> = Suit::iter().map(|suit| theme.color(suit)).collect();
+        assert_eq!(colors.len(), Suit::COUNT);
+        assert_eq!(theme.hearts, Color::Rgb(213, 94, 0));
+    }
+
+    #[test]
+    fn four_color_matches_suit_color() {
+        for suit in Suit::iter() {
+            assert_eq!(SuitTheme::four_color().color(suit), suit.color());
+        }
+    }
+
+    #[test]
+    fn resolved_for_keeps_legible_colors() {
+        for theme in [
+            SuitTheme::four_color(),
+            SuitTheme::two_color(),
+            SuitTheme::colorblind_friendly(),
+        ] {
+            assert_eq!(theme.resolved_for(Color::White), theme);
+            assert_eq!(theme.resolved_for(Color::Reset), theme);
+        }
+    }
+
+    #[test]
+    fn resolved_for_dark_background() {
+        let theme = SuitTheme::four_color().resolved_for(Color::Black);
+        assert_eq!(theme.spades, Color::White);
+        assert_eq!(theme.hearts, Color::Red);
+        assert_eq!(theme.diamonds, Color::Blue);
+        assert_eq!(theme.clubs, Color::Green);
+        let theme = SuitTheme::colorblind_friendly().resolved_for(Color::Rgb(0x10, 0x10, 0x10));
+        assert_eq!(theme.spades, Color::White);
+    }
+
+    #[test]
+    fn resolved_for_light_background() {
+        let theme = SuitTheme {
+            spades: Color::White,
+            hearts: Color::LightYellow,
+            diamonds: Color::Indexed(231),
+            clubs: Color::Reset,
+        }
+        .resolved_for(Color::Indexed(255));
+        assert_eq!(theme.spades, Color::Black);
+        assert_eq!(theme.hearts, Color::Black);
+        assert_eq!(theme.diamonds, Color::Black);
+        assert_eq!(theme.clubs, Color::Reset);
+    }
+
+    #[test]
+    fn cards_render_in_resolved_colors() {
+        let theme = CardTheme::new().background(Color::Black);
+        let card = Card::new(Rank::Ace, Suit::Spades).size(CardSize::Glyph);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buf.render_card(&card, buf.area, theme);
+        assert_eq!(buf[(0, 0)].fg, Color::White);
+        assert_eq!(buf[(0, 0)].bg, Color::Black);
+    }
+
+    #[test]
+    fn render_large_ace_pip() {
+        let card = Card::new(Rank::Ace, Suit::Diamonds);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        buf.render_card(&card, buf.area, CardTheme::new().ace_pip(AcePip::Large));
+        let mut expected = Buffer::with_lines([
+            "╭────────────╮",
+            "│ A          │",
+            "│     ██     │",
+            "│   ██████   │",
+            "│ ██████████ │",
+            "│   ██████   │",
+            "│     ██     │",
+            "│          A │",
+            "╰────────────╯",
+        ]);
+        expected.set_style(expected.area, Style::new().blue().on_white());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn large_ace_pip_ignores_other_ranks() {
+        let theme = CardTheme::new().ace_pip(AcePip::Large);
+        let card = Card::new(Rank::Two, Suit::Clubs);
+        let mut themed = Buffer::empty(Rect::new(0, 0, 14, 9));
+        let mut plain = themed.clone();
+        themed.render_card(&card, themed.area, theme);
+        plain.render_card(&card, plain.area, CardTheme::new());
+        assert_eq!(themed, plain);
+    }
+
+    #[test]
+    fn border_background_only_applies_to_border() {
+        let theme = CardTheme::new()
+            .background(Color::White)
+            .border_background(Color::Gray);
+        let card = Card::new(Rank::Five, Suit::Spades).size(CardSize::Small);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+        buf.render_card(&card, buf.area, theme);
+        for position in Rect::new(0, 0, 8, 5).positions() {
+            let border = position.x == 0 || position.x == 7 || position.y == 0 || position.y == 4;
+            let expected = if border { Color::Gray } else { Color::White };
+            // the cell hidden by the wide suit symbol keeps its reset style
+            if position != (4, 2).into() {
+                assert_eq!(buf[position].bg, expected, "{position}");
+            }
+        }
+        // nothing is drawn outside the card
+        assert_eq!(buf[(8, 0)].bg, Color::Reset);
+        assert_eq!(buf[(0, 5)].bg, Color::Reset);
+    }
+
+    #[test]
+    fn border_background_defaults_to_background() {
+        let theme = CardTheme::new().background(Color::Yellow);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        buf.render_card(&Card::new(Rank::Nine, Suit::Clubs), buf.area, theme);
+        assert_eq!(buf[(0, 0)].bg, Color::Yellow);
+        assert_eq!(buf[(1, 1)].bg, Color::Yellow);
+    }
+
+    #[test]
+    fn corner_and_pip_symbols_are_independent() {
+        let theme = CardTheme::new()
+            .corner_suit_symbol(SymbolPresentation::Plain)
+            .pip_suit_symbol(SymbolPresentation::Text);
+        let card = Card::new(Rank::Two, Suit::Hearts).size(CardSize::Large);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 13));
+        buf.render_card(&card, buf.area, theme);
+        assert_eq!(buf[(3, 1)].symbol(), "♥");
+        assert_eq!(buf[(4, 1)].symbol(), " ");
+        assert_eq!(buf[(9, 2)].symbol(), "\u{2665}\u{FE0E}");
+        assert_eq!(buf[(15, 11)].symbol(), "♥");
+        assert_eq!(buf[(17, 11)].symbol(), "2");
+    }
+
+    #[test]
+    fn default_symbols_match_four_color() {
+        let card = Card::new(Rank::King, Suit::Clubs);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        buf.render_card(&card, buf.area, CardTheme::new());
+        assert_eq!(buf[(3, 1)].symbol(), Suit::Clubs.as_four_color_symbol());
+        assert_eq!(buf[(9, 7)].symbol(), Suit::Clubs.as_four_color_symbol());
+    }
+
+    #[test]
+    fn double_pips_on_large_cards() {
+        let card = Card::new(Rank::Four, Suit::Spades).size(CardSize::Large);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 13));
+        buf.render_card(
+            &card,
+            buf.area,
+            CardTheme::new().pip_scale(PipScale::Double),
+        );
+        let spade = Suit::Spades.as_four_color_symbol();
+        for x in [4, 6, 12, 14] {
+            assert_eq!(buf[(x, 2)].symbol(), spade, "{x}");
+            assert_eq!(buf[(x, 10)].symbol(), spade, "{x}");
+        }
+        assert_eq!(buf[(8, 2)].symbol(), " ");
+        // the corner symbols stay single
+        assert_eq!(buf[(3, 1)].symbol(), spade);
+        assert_eq!(buf[(5, 1)].symbol(), " ");
+    }
+
+    #[test]
+    fn double_pips_ignore_smaller_sizes() {
+        let card = Card::new(Rank::Four, Suit::Spades);
+        let mut single = Buffer::empty(Rect::new(0, 0, 14, 9));
+        let mut double = single.clone();
+        single.render_card(&card, single.area, CardTheme::new());
+        double.render_card(
+            &card,
+            double.area,
+            CardTheme::new().pip_scale(PipScale::Double),
+        );
+        assert_eq!(double, single);
+    }
+
+    #[test]
+    fn render_card_applies_theme() {
+        let card = Card::new(Rank::Ace, Suit::Diamonds);
+        let theme = CardTheme::new()
+            .suits(SuitTheme::two_color())
+            .background(Color::Gray);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 9));
+        buf.render_card(&card, buf.area, theme);
+        assert_eq!(buf[(2, 1)].symbol(), "A");
+        assert_eq!(buf[(2, 1)].fg, Color::Red);
+        assert_eq!(buf[(2, 1)].bg, Color::Gray);
+        // the card is unchanged
+        assert_eq!(card.theme, CardTheme::default());
+    }
+
+    #[test]
+    fn color_names() {
+        assert_eq!(color_name(Color::LightBlue), "light blue");
+        assert_eq!(color_name(Color::Indexed(196)), "red");
+        assert_eq!(color_name(Color::Rgb(0x80, 0x80, 0x80)), "dark gray");
+        let names = Suit::iter()
+            .map(|suit| color_name(SuitTheme::colorblind_friendly().color(suit)))
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["black", "orange", "blue", "magenta"]);
+    }
+}
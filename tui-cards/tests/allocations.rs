@@ -0,0 +1,80 @@
+//! Checks that rendering cards with the default options does not allocate, by counting the
+//! allocations made on the rendering thread with a counting global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use itertools::Itertools;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use strum::IntoEnumIterator;
+use tui_cards::{Card, CardSize, CardTheme, Facing, JokerColor, Rank, Suit, SymbolPresentation};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: every call is forwarded to the system allocator unchanged
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made on this thread while rendering the cards.
+fn allocations_rendering(cards: &[Card], buf: &mut Buffer) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    for card in cards {
+        card.render(buf.area, buf);
+    }
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn deck(size: CardSize) -> Vec<Card> {
+    Rank::iter()
+        .cartesian_product(Suit::iter())
+        .map(|(rank, suit)| Card::new(rank, suit).size(size))
+        .collect()
+}
+
+#[test]
+fn rendering_default_cards_does_not_allocate() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 12));
+    for size in CardSize::iter() {
+        let mut cards = deck(size);
+        cards.extend(deck(size).iter().map(|card| card.facing(Facing::Down)));
+        cards.extend(JokerColor::iter().map(|color| Card::joker(color, size)));
+        // the cached faces are built on the first render
+        allocations_rendering(&cards, &mut buf);
+        assert_eq!(allocations_rendering(&cards, &mut buf), 0, "{size:?}");
+    }
+}
+
+#[test]
+fn rendering_uncached_faces_allocates() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 12));
+    // a non-default pip symbol keeps the face out of the cache, so it is built on every render
+    let theme = CardTheme::new().pip_suit_symbol(SymbolPresentation::Emoji);
+    let cards = deck(CardSize::Normal)
+        .into_iter()
+        .map(|card| card.theme(theme))
+        .collect_vec();
+    allocations_rendering(&cards, &mut buf);
+    assert!(allocations_rendering(&cards, &mut buf) >= cards.len());
+}